@@ -1,39 +1,180 @@
-use std::{env::current_dir, fs::read_to_string};
+use std::{
+    env::current_dir,
+    fs::read_to_string,
+    io::Error,
+    process::{exit, Command as Process, ExitStatus},
+};
 
-use clap::{Parser, Subcommand};
-use envl::{generator::generate_file, load_envl_core, misc::filesystem::write_file};
+use clap::{Parser, Subcommand, ValueEnum};
+use envl::{
+    check_envl,
+    export::{export_vars, flatten_vars, ExportFormat, DEFAULT_SEPARATOR},
+    generator::{generate_file, json_schema::generate_json_schema},
+    load_envl_core,
+    misc::{
+        diagnostic::render,
+        error::{convert_envl_vars_error, convert_io_error, EnvlError},
+        filesystem::write_file,
+    },
+};
+use envl_config::generate_ast as gen_config_ast;
 
 #[derive(Parser, Debug, Clone)]
 #[command(version, about, flatten_help = true)]
 struct Args {
     #[command(subcommand)]
     command: Command,
+    #[arg(long, global = true)]
+    profile: Option<String>,
 }
 
 #[derive(Subcommand, Clone, Debug)]
 pub enum Command {
-    Build { output: String },
+    Build {
+        output: String,
+    },
+    Check,
+    Schema {
+        output: String,
+    },
+    Export {
+        #[arg(long, value_enum, default_value_t = Format::Dotenv)]
+        format: Format,
+        #[arg(long, default_value = DEFAULT_SEPARATOR)]
+        separator: String,
+        #[arg(long)]
+        output: Option<String>,
+    },
+    Exec {
+        #[arg(long, default_value = DEFAULT_SEPARATOR)]
+        separator: String,
+        #[arg(required = true, trailing_var_arg = true, allow_hyphen_values = true)]
+        command: Vec<String>,
+    },
 }
 
-fn get_config_file() -> String {
-    let current_dir = current_dir().unwrap();
-    let config_path = current_dir.join(".envlconf").display().to_string();
+#[derive(ValueEnum, Clone, Copy, Debug)]
+pub enum Format {
+    Dotenv,
+    Shell,
+}
+
+impl From<Format> for ExportFormat {
+    fn from(format: Format) -> Self {
+        match format {
+            Format::Dotenv => ExportFormat::Dotenv,
+            Format::Shell => ExportFormat::Shell,
+        }
+    }
+}
+
+fn get_config_file(config_path: &str) -> String {
+    read_to_string(config_path).unwrap_or_else(|err| {
+        exit_with_errors(vec![convert_io_error(err).with_help(format!(
+            "{} couldn't be read; run envl in the directory containing it",
+            config_path
+        ))])
+    })
+}
+
+fn exit_with_errors(errors: Vec<EnvlError>) -> ! {
+    exit_with_code(errors, 1);
+}
 
-    read_to_string(config_path).unwrap()
+fn exit_with_code(errors: Vec<EnvlError>, code: i32) -> ! {
+    for err in errors.iter() {
+        eprintln!("{}\n", render(err));
+    }
+    exit(code);
+}
+
+fn exit_with_io_error(err: Error) -> ! {
+    exit_with_errors(vec![convert_io_error(err)]);
+}
+
+/// Follows the shell convention of 128 + signal for a child killed by a signal
+fn exit_code(status: ExitStatus) -> i32 {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        if let Some(signal) = status.signal() {
+            return 128 + signal;
+        }
+    }
+
+    status.code().unwrap_or(1)
 }
 
 fn main() {
     let args = Args::parse();
-    let current_dir = current_dir().unwrap();
+    let current_dir = current_dir().unwrap_or_else(|err| exit_with_io_error(err));
     let config_path = current_dir.join(".envlconf").display().to_string();
-    let config_code = get_config_file();
+    let config_code = get_config_file(&config_path);
 
     match args.command {
         Command::Build { output } => {
-            let data = load_envl_core(current_dir.clone(), config_path, config_code).unwrap();
+            let data = load_envl_core(current_dir.clone(), config_path, config_code, args.profile)
+                .unwrap_or_else(|errors| exit_with_errors(errors));
+
+            let f =
+                generate_file(data, output.clone()).unwrap_or_else(|err| exit_with_io_error(err));
+            write_file(current_dir.join(output).display().to_string(), f)
+                .unwrap_or_else(|err| exit_with_io_error(err));
+        }
+        Command::Check => {
+            let errors = check_envl(current_dir.clone(), config_path, config_code, args.profile);
+
+            if errors.is_empty() {
+                println!("No problems found");
+            } else {
+                exit_with_errors(errors);
+            }
+        }
+        Command::Schema { output } => {
+            let config = gen_config_ast(config_path, config_code).unwrap_or_else(|errors| {
+                exit_with_errors(errors.into_iter().map(convert_envl_vars_error).collect())
+            });
+
+            let f = generate_json_schema(config).unwrap_or_else(|err| exit_with_io_error(err));
+            write_file(current_dir.join(output).display().to_string(), f)
+                .unwrap_or_else(|err| exit_with_io_error(err));
+        }
+        Command::Export {
+            format,
+            separator,
+            output,
+        } => {
+            let data = load_envl_core(current_dir.clone(), config_path, config_code, args.profile)
+                .unwrap_or_else(|errors| exit_with_errors(errors));
+
+            let f = export_vars(data, format.into(), &separator);
+            if let Some(output) = output {
+                write_file(current_dir.join(output).display().to_string(), f)
+                    .unwrap_or_else(|err| exit_with_io_error(err));
+            } else {
+                println!("{}", f);
+            }
+        }
+        Command::Exec { separator, command } => {
+            let data = load_envl_core(current_dir.clone(), config_path, config_code, args.profile)
+                .unwrap_or_else(|errors| exit_with_errors(errors));
 
-            let f = generate_file(data, output.clone()).unwrap();
-            write_file(current_dir.join(output).display().to_string(), f).unwrap();
+            let status = Process::new(&command[0])
+                .args(&command[1..])
+                .envs(flatten_vars(data, &separator))
+                .status();
+            match status {
+                Ok(status) => exit(exit_code(status)),
+                // 127 is what shells exit with when a command can't be run
+                Err(err) => exit_with_code(
+                    vec![convert_io_error(err).with_help(format!(
+                        "{} couldn't be run; check that it is installed and on PATH",
+                        command[0]
+                    ))],
+                    127,
+                ),
+            }
         }
     }
 }
@@ -14,8 +14,12 @@ impl Lexer {
             "float" => Value::Type(Type::Float),
             "settings" => Value::Settings,
             "vars" => Value::Vars,
+            "types" => Value::Types,
             "struct" => Value::Struct,
+            "enum" => Value::Enum,
             "Array" => Value::Array,
+            "Map" => Value::Map,
+            "import" => Value::Import,
             "Option" => Value::Option,
             "null" => Value::Null,
             other => Value::Ident(other.to_string()),
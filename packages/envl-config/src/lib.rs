@@ -7,9 +7,9 @@ pub mod misc;
 pub mod parser;
 pub mod test;
 
-pub fn generate_ast(file_path: String, code: String) -> Result<Config, EnvlError> {
+pub fn generate_ast(file_path: String, code: String) -> Result<Config, Vec<EnvlError>> {
     let lex = Lexer::new(file_path.clone(), code);
-    let tokens = lex.generate();
+    let tokens = lex.generate()?;
     let parser = Parser::new(file_path, tokens);
     parser.parse()
 }
@@ -25,11 +25,32 @@ pub struct SettingsWithoutPosition {
     pub envl_file_path: Option<SettingWithoutPotision<String>>,
 }
 
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Constraints {
+    pub min: Option<Value>,
+    pub max: Option<Value>,
+    pub min_len: Option<u64>,
+    pub max_len: Option<u64>,
+    pub pattern: Option<String>,
+    pub one_of: Option<Vec<Value>>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    Trim,
+    Lowercase,
+    Uppercase,
+    ExpandHome,
+    Split(String),
+}
+
 #[derive(Debug, Clone)]
 pub struct Var<T = Type, U = Value> {
     pub v_type: T,
     pub default_value: U,
-    pub actions_value: U,
+    pub actions: Vec<Action>,
+    pub env: Option<String>,
+    pub constraints: Constraints,
     pub position: Position,
 }
 
@@ -37,7 +58,9 @@ pub struct Var<T = Type, U = Value> {
 pub struct VarWithoutPosition<T = Type, U = Value> {
     pub v_type: T,
     pub default_value: U,
-    pub actions_value: U,
+    pub actions: Vec<Action>,
+    pub env: Option<String>,
+    pub constraints: Constraints,
 }
 
 pub type Vars = HashMap<String, Var>;
@@ -80,7 +103,9 @@ pub fn remove_position_prop(config: Config) -> ConfigWithoutPosition {
                     VarWithoutPosition {
                         v_type: v.v_type.to_owned(),
                         default_value: v.default_value.to_owned(),
-                        actions_value: v.actions_value.to_owned(),
+                        actions: v.actions.to_owned(),
+                        env: v.env.to_owned(),
+                        constraints: v.constraints.to_owned(),
                     },
                 )
             })
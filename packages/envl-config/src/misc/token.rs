@@ -17,13 +17,17 @@ pub enum Value {
     LeftShift,
     Settings,
     Struct,
+    Enum,
     Option,
     Array,
+    Map,
+    Import,
     Comma,
     Colon,
     Equal,
     Null,
     Vars,
+    Types,
     Semi,
 }
 
@@ -1,4 +1,7 @@
-use std::collections::HashMap;
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Type {
@@ -12,6 +15,38 @@ pub enum Type {
     Array(Box<Type>),
     Struct(HashMap<String, Type>),
     Option(Box<Type>),
+    Enum(Vec<String>),
+    Map(Box<Type>),
+    Named(String, Box<Type>),
+}
+
+impl fmt::Display for Type {
+    /// Writes the type the way it is spelled in `.envlconf`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::Null => write!(f, "null"),
+            Type::String => write!(f, "string"),
+            Type::Char => write!(f, "char"),
+            Type::Float => write!(f, "float"),
+            Type::Int => write!(f, "int"),
+            Type::Uint => write!(f, "uint"),
+            Type::Bool => write!(f, "bool"),
+            Type::Array(t) => write!(f, "Array<{}>", t),
+            Type::Struct(elements) => {
+                let mut names = elements.keys().collect::<Vec<_>>();
+                names.sort();
+                write!(f, "struct {{")?;
+                for name in names {
+                    write!(f, " {}: {};", name, elements[name])?;
+                }
+                write!(f, " }}")
+            }
+            Type::Option(t) => write!(f, "Option<{}>", t),
+            Type::Enum(variants) => write!(f, "enum {{ {} }}", variants.join(", ")),
+            Type::Map(t) => write!(f, "Map<string, {}>", t),
+            Type::Named(name, _) => write!(f, "{}", name),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -25,4 +60,5 @@ pub enum Value {
     Bool(bool),
     Array(Vec<Value>),
     Struct(HashMap<String, Value>),
+    Map(BTreeMap<String, Value>),
 }
@@ -0,0 +1,123 @@
+use std::{
+    collections::HashMap,
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    slice::Iter,
+};
+
+use envl_utils::{
+    error::{EnvlError, ErrorContext},
+    types::Position,
+};
+
+use crate::{
+    lexer::Lexer,
+    misc::{
+        config::Vars,
+        token::{Token, Value},
+        variable::{Type, Value as ConfigValue},
+    },
+    parser::{value::parse_value, Parser},
+};
+
+pub fn merge_vars(base: &mut Vars, vars: Vars) -> Result<(), EnvlError> {
+    for (name, var) in vars {
+        if let Some(existing) = base.get(&name) {
+            if existing.position != var.position {
+                return Err(EnvlError {
+                    message: ErrorContext::DuplicateAt(name, existing.position.clone()),
+                    position: var.position,
+                });
+            }
+        }
+        base.insert(name, var);
+    }
+
+    Ok(())
+}
+
+impl Parser {
+    pub fn merge_types(
+        &self,
+        types: HashMap<String, Type>,
+        position: &Position,
+    ) -> Result<(), EnvlError> {
+        let mut current_types = self.types.borrow_mut();
+
+        for (name, t) in types {
+            if let Some(existing) = current_types.get(&name) {
+                if *existing != t {
+                    return Err(EnvlError {
+                        message: ErrorContext::Duplicate(name),
+                        position: position.clone(),
+                    });
+                }
+            }
+            current_types.insert(name, t);
+        }
+
+        Ok(())
+    }
+
+    pub fn parse_import<'a>(
+        &self,
+        tokens: &mut Iter<'a, Token>,
+        position: &Position,
+    ) -> Result<(Vars, HashMap<String, Type>), Vec<EnvlError>> {
+        let error = |message: ErrorContext| {
+            vec![EnvlError {
+                message,
+                position: position.clone(),
+            }]
+        };
+
+        let import_path = match tokens.next() {
+            Some(Token {
+                value: Value::Ident(v),
+                ..
+            }) => match parse_value(Type::String, v.to_owned()) {
+                Ok(ConfigValue::String(s)) => s,
+                _ => {
+                    return Err(error(ErrorContext::InvalidType));
+                }
+            },
+            _ => {
+                return Err(error(ErrorContext::Required("File path".to_string())));
+            }
+        };
+        match tokens.next() {
+            Some(Token {
+                value: Value::Semi, ..
+            }) => {}
+            _ => {
+                return Err(error(ErrorContext::Required("Semicolon".to_string())));
+            }
+        }
+
+        let current_file = Path::new(&self.file_path);
+        let file_path = match current_file.parent() {
+            Some(parent) => parent.join(&import_path),
+            None => PathBuf::from(&import_path),
+        };
+        let canonical_path = file_path
+            .canonicalize()
+            .map_err(|_| error(ErrorContext::IncludeNotFound(import_path.to_owned())))?;
+
+        let mut imported_files = self.imported_files.to_owned();
+        if let Ok(current_file) = current_file.canonicalize() {
+            imported_files.push(current_file);
+        }
+        if imported_files.contains(&canonical_path) {
+            return Err(error(ErrorContext::IncludeCycle(import_path)));
+        }
+
+        let code = read_to_string(&canonical_path)
+            .map_err(|_| error(ErrorContext::IncludeNotFound(import_path.to_owned())))?;
+        let file_path = file_path.display().to_string();
+        let lexer = Lexer::new(file_path.to_owned(), code);
+        let mut parser = Parser::new(file_path, lexer.generate()?);
+        parser.imported_files = imported_files;
+
+        parser.parse_imported()
+    }
+}
@@ -1,14 +1,22 @@
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, slice::Iter};
+
 use envl_utils::{
     error::{EnvlError, ErrorContext},
     types::Position,
 };
 
-use crate::misc::{
-    config::Config,
-    token::{Token, Value},
+use crate::{
+    misc::{
+        config::{Config, Settings, Vars},
+        token::{Token, Value},
+        variable::Type,
+    },
+    parser::import::merge_vars,
 };
 
+pub mod import;
 pub mod settings;
+pub mod types;
 pub mod value;
 pub mod var;
 pub mod vars;
@@ -16,14 +24,59 @@ pub mod vars;
 pub struct Parser {
     pub file_path: String,
     pub tokens: Vec<Token>,
+    pub imported_files: Vec<PathBuf>,
+    pub types: RefCell<HashMap<String, Type>>,
 }
 
 impl Parser {
     pub fn new(file_path: String, tokens: Vec<Token>) -> Self {
-        Self { file_path, tokens }
+        Self {
+            file_path,
+            tokens,
+            imported_files: Vec::new(),
+            types: RefCell::new(HashMap::new()),
+        }
+    }
+
+    pub fn named_type(&self, name: &str) -> Option<Type> {
+        self.types
+            .borrow()
+            .get(name)
+            .map(|t| Type::Named(name.to_string(), Box::from(t.to_owned())))
     }
 
-    pub fn parse(&self) -> Result<Config, EnvlError> {
+    pub fn parse(&self) -> Result<Config, Vec<EnvlError>> {
+        let (settings, vars, imported_vars) = self.parse_blocks()?;
+
+        match (vars, settings) {
+            (Some(vars), Some(settings)) => {
+                let mut merged_vars = imported_vars;
+                merge_vars(&mut merged_vars, vars).map_err(|err| vec![err])?;
+                Ok(Config {
+                    settings,
+                    vars: merged_vars,
+                })
+            }
+            _ => Err(vec![EnvlError {
+                message: ErrorContext::Required("Settings and vars".to_string()),
+                position: Position {
+                    file_path: self.file_path.clone(),
+                    row: 0,
+                    col: 0,
+                },
+            }]),
+        }
+    }
+
+    pub fn parse_imported(&self) -> Result<(Vars, HashMap<String, Type>), Vec<EnvlError>> {
+        let (_, vars, imported_vars) = self.parse_blocks()?;
+
+        let mut merged_vars = imported_vars;
+        merge_vars(&mut merged_vars, vars.unwrap_or_default()).map_err(|err| vec![err])?;
+        Ok((merged_vars, self.types.borrow().to_owned()))
+    }
+
+    fn parse_blocks(&self) -> Result<(Option<Settings>, Option<Vars>, Vars), Vec<EnvlError>> {
         let mut based_token = vec![];
 
         for token in self.tokens.iter() {
@@ -37,63 +90,134 @@ impl Parser {
             }
         }
 
-        let mut tokens = based_token.iter();
-        let mut parser_error = None;
+        let mut errors = Vec::new();
         let mut vars = None;
         let mut settings = None;
+        let mut imported_vars = HashMap::new();
 
-        'parse_loop: loop {
-            macro_rules! error {
-                ($err: expr) => {
-                    parser_error = Some($err);
+        // Imports and types are read first, so vars can use a type declared
+        // anywhere in the file
+        for is_definition_pass in [true, false] {
+            let mut tokens = based_token.iter();
+
+            'parse_loop: loop {
+                let statement_start = tokens.clone();
+
+                // Skips the rest of the broken block or statement and keeps parsing from there
+                macro_rules! error {
+                    ($errs: expr) => {
+                        errors.extend($errs);
+                        tokens = statement_start.clone();
+                        skip_statement(&mut tokens);
+                        continue 'parse_loop;
+                    };
+                }
+
+                let Some(token) = tokens.next() else {
                     break 'parse_loop;
                 };
-            }
+                let is_definition = matches!(token.value, Value::Import | Value::Types);
+                if is_definition != is_definition_pass {
+                    // The other pass reads this block
+                    if is_definition || matches!(token.value, Value::Vars | Value::Settings) {
+                        tokens = statement_start.clone();
+                        skip_statement(&mut tokens);
+                    }
+                    continue 'parse_loop;
+                }
 
-            if let Some(token) = tokens.next() {
                 match token.value {
                     Value::Vars => match self.parse_vars(&mut tokens) {
                         Ok(result) => {
                             vars = Some(result);
                         }
-                        Err(err) => {
-                            error!(err);
+                        Err(errs) => {
+                            error!(errs);
+                        }
+                    },
+                    Value::Import => match self.parse_import(&mut tokens, &token.position) {
+                        Ok((result, types)) => {
+                            if let Err(err) = merge_vars(&mut imported_vars, result) {
+                                error!([err]);
+                            }
+                            if let Err(err) = self.merge_types(types, &token.position) {
+                                error!([err]);
+                            }
+                        }
+                        Err(errs) => {
+                            error!(errs);
                         }
                     },
+                    Value::Types => {
+                        if let Err(errs) = self.parse_types(&mut tokens) {
+                            error!(errs);
+                        }
+                    }
                     Value::Settings => match self.parse_settings(&mut tokens) {
                         Ok(result) => {
                             settings = Some(result);
                         }
-                        Err(err) => {
-                            error!(err);
+                        Err(errs) => {
+                            error!(errs);
                         }
                     },
                     _ => {
-                        error!(EnvlError {
+                        errors.push(EnvlError {
                             message: ErrorContext::InvalidSettingsSyntax,
-                            position: token.position.clone()
+                            position: token.position.clone(),
                         });
                     }
                 }
-            } else {
-                break 'parse_loop;
             }
         }
 
-        if let Some(err) = parser_error {
-            return Err(err);
+        if errors.is_empty() {
+            Ok((settings, vars, imported_vars))
+        } else {
+            Err(errors)
         }
+    }
+}
 
-        match (vars, settings) {
-            (Some(vars), Some(settings)) => Ok(Config { settings, vars }),
-            _ => Err(EnvlError {
-                message: ErrorContext::Required("Settings and vars".to_string()),
-                position: Position {
-                    file_path: self.file_path.clone(),
-                    row: 0,
-                    col: 0,
-                },
-            }),
+/// Moves past a broken element of a block, ending after the next `separator` at
+/// the block's own depth. Returns `true` when the block's `close` comes first
+fn skip_element(tokens: &mut Iter<'_, Token>, separator: &Value, close: &Value) -> bool {
+    let mut depth: usize = 0;
+
+    for token in tokens.by_ref() {
+        match &token.value {
+            value if depth == 0 && value == close => return true,
+            value if depth == 0 && value == separator => return false,
+            Value::LeftCurlyBracket
+            | Value::LeftParentheses
+            | Value::LeftShift
+            | Value::LeftSquareBracket => depth += 1,
+            Value::RightCurlyBracket
+            | Value::RightParentheses
+            | Value::RightShift
+            | Value::RightSquareBracket => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// Moves past a top-level block or statement, ending after its closing `}` or `;`
+fn skip_statement(tokens: &mut Iter<'_, Token>) {
+    let mut depth = 0;
+
+    for token in tokens.by_ref() {
+        match token.value {
+            Value::LeftCurlyBracket => depth += 1,
+            Value::RightCurlyBracket => {
+                depth -= 1;
+                if depth <= 0 {
+                    break;
+                }
+            }
+            Value::Semi if depth == 0 => break,
+            _ => {}
         }
     }
 }
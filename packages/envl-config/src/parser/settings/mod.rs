@@ -10,7 +10,7 @@ use crate::{
         config::{Setting, Settings},
         token::{Token, Value},
     },
-    parser::Parser,
+    parser::{skip_element, Parser},
 };
 
 impl Parser {
@@ -28,7 +28,10 @@ impl Parser {
         }
     }
 
-    pub fn parse_settings<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<Settings, EnvlError> {
+    pub fn parse_settings<'a>(
+        &self,
+        tokens: &mut Iter<'a, Token>,
+    ) -> Result<Settings, Vec<EnvlError>> {
         let mut in_block = false;
         let mut block_closed = false;
         let mut equal_used = false;
@@ -36,20 +39,44 @@ impl Parser {
         let mut target_prop = None;
         let mut target_value = None;
 
-        let mut parser_error = None;
+        let mut errors = Vec::new();
         let mut settings = Settings {
             envl_file_path: None,
         };
+        let mut element_start = tokens.clone();
 
         'parse_loop: loop {
+            if target_prop.is_none() {
+                element_start = tokens.clone();
+            }
+
             if let Some(token) = tokens.next() {
+                // Skips to the next setting of the block and keeps parsing from there
+                macro_rules! recover {
+                    ($err: expr) => {
+                        errors.push($err);
+                        if !in_block {
+                            break 'parse_loop;
+                        }
+
+                        *tokens = element_start.clone();
+                        target_prop = None;
+                        target_value = None;
+                        equal_used = false;
+
+                        if skip_element(tokens, &Value::Semi, &Value::RightCurlyBracket) {
+                            block_closed = true;
+                            break 'parse_loop;
+                        }
+                        continue 'parse_loop;
+                    };
+                }
                 macro_rules! error {
                     ($msg: expr) => {
-                        parser_error = Some(EnvlError {
+                        recover!(EnvlError {
                             message: $msg,
                             position: token.position.clone(),
                         });
-                        break 'parse_loop;
                     };
                 }
 
@@ -96,14 +123,16 @@ impl Parser {
                                         });
                                     }
                                     Err(err) => {
-                                        parser_error = Some(err);
-                                        break 'parse_loop;
+                                        recover!(err);
                                     }
                                 },
                                 _ => {
                                     error!(ErrorContext::InvalidProperty("settings".to_string()));
                                 }
                             }
+                            target_prop = None;
+                            target_value = None;
+                            equal_used = false;
                         }
                     }
                     Value::Ident(v) => {
@@ -122,19 +151,19 @@ impl Parser {
             }
         }
 
-        if let Some(err) = parser_error {
-            Err(err)
-        } else {
-            if let Some(position) = last_position {
-                if !block_closed {
-                    return Err(EnvlError {
-                        message: ErrorContext::IsntClosed("Settings".to_string()),
-                        position,
-                    });
-                }
+        if let Some(position) = last_position {
+            if !block_closed && (in_block || errors.is_empty()) {
+                errors.push(EnvlError {
+                    message: ErrorContext::IsntClosed("Settings".to_string()),
+                    position,
+                });
             }
+        }
 
+        if errors.is_empty() {
             Ok(settings)
+        } else {
+            Err(errors)
         }
     }
 }
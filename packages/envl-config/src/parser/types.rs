@@ -0,0 +1,202 @@
+use std::slice::Iter;
+
+use envl_utils::{
+    error::{EnvlError, ErrorContext},
+    name::{is_reserved_type_name, is_valid_variable_name},
+};
+
+use crate::{
+    misc::token::{Token, Value},
+    parser::{skip_element, Parser},
+};
+
+impl Parser {
+    pub fn parse_types<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<(), Vec<EnvlError>> {
+        let mut in_block = false;
+        let mut block_closed = false;
+        let mut colon_used = false;
+        let mut comma_used = false;
+        let mut inserted_count = 0;
+        let mut element_name: Option<String> = None;
+        let mut last_position = None;
+
+        let mut errors = Vec::new();
+        let mut element_start = tokens.clone();
+
+        'parse_loop: loop {
+            if element_name.is_none() && !colon_used {
+                element_start = tokens.clone();
+            }
+
+            if let Some(token) = tokens.next() {
+                // Skips to the next element of the block and keeps parsing from there
+                macro_rules! recover {
+                    ($errs: expr) => {
+                        errors.extend($errs);
+                        if !in_block {
+                            break 'parse_loop;
+                        }
+
+                        *tokens = element_start.clone();
+                        element_name = None;
+                        colon_used = false;
+
+                        if skip_element(tokens, &Value::Comma, &Value::RightCurlyBracket) {
+                            block_closed = true;
+                            break 'parse_loop;
+                        }
+                        comma_used = true;
+                        continue 'parse_loop;
+                    };
+                }
+                macro_rules! error {
+                    ($msg: expr) => {
+                        recover!([EnvlError {
+                            message: $msg,
+                            position: token.position.clone(),
+                        }]);
+                    };
+                }
+                macro_rules! insert {
+                    ($value: expr) => {
+                        if let Some(name) = element_name {
+                            if !colon_used {
+                                error!(ErrorContext::Required("Colon".to_string()));
+                            }
+                            if inserted_count != 0 && !comma_used {
+                                error!(ErrorContext::Required("Comma".to_string()));
+                            }
+                            if self.types.borrow().contains_key(&name) {
+                                error!(ErrorContext::Duplicate(name));
+                            }
+                            self.types.borrow_mut().insert(name, $value);
+                            element_name = None;
+                            colon_used = false;
+                            comma_used = false;
+                            inserted_count += 1;
+                        } else {
+                            error!(ErrorContext::Required("Element name".to_string()));
+                        }
+                    };
+                }
+
+                last_position = Some(token.position.clone());
+
+                match &token.value {
+                    Value::LeftCurlyBracket => {
+                        if in_block {
+                            error!(ErrorContext::InvalidPosition("{".to_string()));
+                        }
+                        in_block = true;
+                        continue;
+                    }
+                    Value::RightCurlyBracket => {
+                        block_closed = true;
+                        break 'parse_loop;
+                    }
+                    _ => {}
+                }
+
+                if !in_block {
+                    error!(ErrorContext::MustInBlock("types".to_string()));
+                }
+
+                match &token.value {
+                    Value::Comma => {
+                        if comma_used || element_name.is_some() {
+                            error!(ErrorContext::InvalidPosition("Comma".to_string()));
+                        }
+                        comma_used = true;
+                    }
+                    Value::Colon => {
+                        if colon_used || element_name.is_none() {
+                            error!(ErrorContext::InvalidPosition("Colon".to_string()));
+                        }
+                        colon_used = true;
+                    }
+                    Value::Ident(v) if element_name.is_some() => match self.named_type(v) {
+                        Some(t) => {
+                            insert!(t);
+                        }
+                        None => {
+                            error!(ErrorContext::UnknownType(v.to_string()));
+                        }
+                    },
+                    Value::Ident(v) => {
+                        if !is_valid_variable_name(v) || is_reserved_type_name(v) {
+                            error!(ErrorContext::InvalidName(v.to_string()));
+                        }
+                        element_name = Some(v.to_owned());
+                    }
+                    Value::Type(t) if element_name.is_none() => {
+                        error!(ErrorContext::InvalidName(t.to_string()));
+                    }
+                    Value::Array if element_name.is_none() => {
+                        error!(ErrorContext::InvalidName("Array".to_string()));
+                    }
+                    Value::Map if element_name.is_none() => {
+                        error!(ErrorContext::InvalidName("Map".to_string()));
+                    }
+                    Value::Option if element_name.is_none() => {
+                        error!(ErrorContext::InvalidName("Option".to_string()));
+                    }
+                    Value::Type(t) => {
+                        insert!(t.to_owned());
+                    }
+                    Value::Array => match self.parse_array(tokens) {
+                        Ok(t) => {
+                            insert!(t);
+                        }
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::Struct => match self.parse_struct(tokens) {
+                        Ok(t) => {
+                            insert!(t);
+                        }
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::Map => match self.parse_map(tokens) {
+                        Ok(t) => {
+                            insert!(t);
+                        }
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::Enum => match self.parse_enum(tokens) {
+                        Ok(t) => {
+                            insert!(t);
+                        }
+                        Err(err) => {
+                            recover!([err]);
+                        }
+                    },
+                    _ => {
+                        error!(ErrorContext::InvalidSyntaxInBlock("types".to_string()));
+                    }
+                }
+            } else {
+                break 'parse_loop;
+            }
+        }
+
+        if let Some(position) = last_position {
+            if !block_closed && (in_block || errors.is_empty()) {
+                errors.push(EnvlError {
+                    message: ErrorContext::IsntClosed("types".to_string()),
+                    position,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
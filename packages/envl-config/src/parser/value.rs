@@ -57,6 +57,21 @@ pub fn parse_value(t: Type, ident: String) -> Result<Value, ErrorContext> {
                 Err(ErrorContext::InvalidType)
             }
         }
+        Type::Enum(variants) => {
+            if ident.starts_with('"') && ident.ends_with('"') {
+                let mut str_value = ident.to_owned();
+                str_value.remove(ident.len() - 1);
+                str_value.remove(0);
+                if variants.contains(&str_value) {
+                    Ok(Value::String(str_value))
+                } else {
+                    Err(ErrorContext::InvalidVariant(str_value))
+                }
+            } else {
+                Err(ErrorContext::InvalidType)
+            }
+        }
+        Type::Named(_, t) => parse_value(*t, ident),
         _ => Err(ErrorContext::InvalidType),
     }
 }
@@ -4,7 +4,10 @@ use envl_utils::error::{EnvlError, ErrorContext};
 
 use crate::{
     misc::token::{Token, Value},
-    parser::{var::parse_struct::parse_struct, vars::option_value::ParsedValue},
+    parser::{
+        var::{map::parse_map, parse_struct::parse_struct},
+        vars::option_value::ParsedValue,
+    },
 };
 
 pub fn parse_array<'a>(tokens: &mut Iter<'a, Token>) -> Result<ParsedValue, EnvlError> {
@@ -79,6 +82,15 @@ pub fn parse_array<'a>(tokens: &mut Iter<'a, Token>) -> Result<ParsedValue, Envl
                         break 'parse_loop;
                     }
                 },
+                Value::Ident(v) if v == "map" => match parse_map(tokens) {
+                    Ok(v) => {
+                        insert!(v);
+                    }
+                    Err(err) => {
+                        parser_error = Some(err);
+                        break 'parse_loop;
+                    }
+                },
                 Value::Ident(v) => {
                     elements.push(ParsedValue::Value(v.to_owned()));
                 }
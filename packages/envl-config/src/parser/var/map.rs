@@ -0,0 +1,157 @@
+use std::{collections::BTreeMap, slice::Iter};
+
+use envl_utils::error::{EnvlError, ErrorContext};
+
+use crate::{
+    misc::{
+        token::{Token, Value},
+        variable::{Type, Value as ConfigValue},
+    },
+    parser::{
+        value::parse_value,
+        var::{array::parse_array, parse_struct::parse_struct},
+        vars::option_value::ParsedValue,
+    },
+};
+
+pub fn parse_map<'a>(tokens: &mut Iter<'a, Token>) -> Result<ParsedValue, EnvlError> {
+    let mut in_block = false;
+    let mut block_closed = false;
+    let mut comma_used = false;
+    let mut colon_used = false;
+    let mut element_key: Option<String> = None;
+
+    let mut elements = BTreeMap::new();
+    let mut last_position = None;
+    let mut parser_error = None;
+
+    'parse_loop: loop {
+        if let Some(token) = tokens.next() {
+            macro_rules! error {
+                ($msg: expr) => {
+                    parser_error = Some(EnvlError {
+                        message: $msg,
+                        position: token.position.clone(),
+                    });
+                    break 'parse_loop;
+                };
+            }
+            macro_rules! insert {
+                ($value: expr) => {
+                    if !colon_used {
+                        error!(ErrorContext::Required("Colon".to_string()));
+                    }
+                    if let Some(key) = element_key.take() {
+                        if !elements.is_empty() && !comma_used {
+                            error!(ErrorContext::Required("Comma".to_string()));
+                        }
+                        if elements.contains_key(&key) {
+                            error!(ErrorContext::InvalidElements);
+                        }
+                        elements.insert(key, $value);
+                    } else {
+                        error!(ErrorContext::Required("Element name".to_string()));
+                    }
+                    comma_used = false;
+                    colon_used = false;
+                };
+            }
+
+            last_position = Some(token.position.to_owned());
+
+            match &token.value {
+                Value::LeftCurlyBracket if !in_block => {
+                    in_block = true;
+                    continue;
+                }
+                Value::RightCurlyBracket => {
+                    block_closed = true;
+                    break 'parse_loop;
+                }
+                _ => {}
+            }
+
+            if !in_block {
+                error!(ErrorContext::InBlock);
+            }
+
+            match &token.value {
+                Value::Comma => {
+                    if comma_used || element_key.is_some() {
+                        error!(ErrorContext::InvalidPosition("Comma".to_string()));
+                    }
+                    comma_used = true;
+                }
+                Value::Colon => {
+                    if colon_used || element_key.is_none() {
+                        error!(ErrorContext::InvalidPosition("Colon".to_string()));
+                    }
+                    colon_used = true;
+                }
+                Value::Null => {
+                    insert!(ParsedValue::Null);
+                }
+                Value::Struct => match parse_struct(tokens) {
+                    Ok(v) => {
+                        insert!(v);
+                    }
+                    Err(err) => {
+                        parser_error = Some(err);
+                        break 'parse_loop;
+                    }
+                },
+                Value::LeftSquareBracket => match parse_array(tokens) {
+                    Ok(v) => {
+                        insert!(v);
+                    }
+                    Err(err) => {
+                        parser_error = Some(err);
+                        break 'parse_loop;
+                    }
+                },
+                Value::Ident(v) if element_key.is_none() => {
+                    match parse_value(Type::String, v.to_owned()) {
+                        Ok(ConfigValue::String(key)) => {
+                            element_key = Some(key);
+                        }
+                        _ => {
+                            error!(ErrorContext::InvalidName(v.to_owned()));
+                        }
+                    }
+                }
+                Value::Ident(v) if v == "map" => match parse_map(tokens) {
+                    Ok(v) => {
+                        insert!(v);
+                    }
+                    Err(err) => {
+                        parser_error = Some(err);
+                        break 'parse_loop;
+                    }
+                },
+                Value::Ident(v) => {
+                    insert!(ParsedValue::Value(v.clone()));
+                }
+                _ => {
+                    error!(ErrorContext::InvalidSyntaxInBlock("map".to_string()));
+                }
+            }
+        } else {
+            break 'parse_loop;
+        }
+    }
+
+    if let Some(err) = parser_error {
+        Err(err)
+    } else {
+        if let Some(position) = last_position {
+            if !block_closed {
+                return Err(EnvlError {
+                    message: ErrorContext::IsntClosed("Map".to_string()),
+                    position,
+                });
+            }
+        }
+
+        Ok(ParsedValue::Map(elements))
+    }
+}
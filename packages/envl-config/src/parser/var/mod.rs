@@ -1,2 +1,3 @@
 pub mod array;
+pub mod map;
 pub mod parse_struct;
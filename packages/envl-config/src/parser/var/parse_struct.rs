@@ -4,7 +4,10 @@ use envl_utils::error::{EnvlError, ErrorContext};
 
 use crate::{
     misc::token::{Token, Value},
-    parser::{var::array::parse_array, vars::option_value::ParsedValue},
+    parser::{
+        var::{array::parse_array, map::parse_map},
+        vars::option_value::ParsedValue,
+    },
 };
 
 pub fn parse_struct<'a>(tokens: &mut Iter<'a, Token>) -> Result<ParsedValue, EnvlError> {
@@ -60,6 +63,9 @@ pub fn parse_struct<'a>(tokens: &mut Iter<'a, Token>) -> Result<ParsedValue, Env
                     } else {
                         error!(ErrorContext::Required("Element name".to_string()));
                     }
+                    element_name = None;
+                    element_value = None;
+                    colon_used = false;
                 };
             }
 
@@ -112,6 +118,17 @@ pub fn parse_struct<'a>(tokens: &mut Iter<'a, Token>) -> Result<ParsedValue, Env
                         break 'parse_loop;
                     }
                 },
+                Value::Ident(v) if v == "map" && element_name.is_some() => {
+                    match parse_map(tokens) {
+                        Ok(v) => {
+                            set_element_value!(v);
+                        }
+                        Err(err) => {
+                            parser_error = Some(err);
+                            break 'parse_loop;
+                        }
+                    }
+                }
                 Value::Ident(v) => {
                     if element_name.is_some() {
                         set_element_value!(ParsedValue::Value(v.clone()));
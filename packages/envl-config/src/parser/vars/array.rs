@@ -14,19 +14,19 @@ use crate::{
 };
 
 impl Parser {
-    pub fn parse_array<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<Type, EnvlError> {
+    pub fn parse_array<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<Type, Vec<EnvlError>> {
         let mut in_block = false;
         let mut block_closed = false;
         let mut last_position = None;
         let mut array_type = None;
 
-        let mut parser_error = None;
+        let mut errors = Vec::new();
 
         'parse_loop: loop {
             if let Some(token) = tokens.next() {
                 macro_rules! error {
                     ($msg: expr) => {
-                        parser_error = Some(EnvlError {
+                        errors.push(EnvlError {
                             message: $msg,
                             position: token.position.clone(),
                         });
@@ -64,8 +64,8 @@ impl Parser {
                             Ok(v) => {
                                 array_type = Some(v);
                             }
-                            Err(err) => {
-                                parser_error = Some(err);
+                            Err(errs) => {
+                                errors.extend(errs);
                                 break 'parse_loop;
                             }
                         }
@@ -78,8 +78,8 @@ impl Parser {
                             Ok(v) => {
                                 array_type = Some(v);
                             }
-                            Err(err) => {
-                                parser_error = Some(err);
+                            Err(errs) => {
+                                errors.extend(errs);
                                 break 'parse_loop;
                             }
                         }
@@ -89,11 +89,39 @@ impl Parser {
                             error!(ErrorContext::InvalidType);
                         }
                         match self.parse_struct(tokens) {
+                            Ok(v) => {
+                                array_type = Some(v);
+                            }
+                            Err(errs) => {
+                                errors.extend(errs);
+                                break 'parse_loop;
+                            }
+                        }
+                    }
+                    Value::Map => {
+                        if array_type.is_some() {
+                            error!(ErrorContext::InvalidType);
+                        }
+                        match self.parse_map(tokens) {
+                            Ok(v) => {
+                                array_type = Some(v);
+                            }
+                            Err(errs) => {
+                                errors.extend(errs);
+                                break 'parse_loop;
+                            }
+                        }
+                    }
+                    Value::Enum => {
+                        if array_type.is_some() {
+                            error!(ErrorContext::InvalidType);
+                        }
+                        match self.parse_enum(tokens) {
                             Ok(v) => {
                                 array_type = Some(v);
                             }
                             Err(err) => {
-                                parser_error = Some(err);
+                                errors.push(err);
                                 break 'parse_loop;
                             }
                         }
@@ -104,6 +132,19 @@ impl Parser {
                         }
                         array_type = Some(t.to_owned());
                     }
+                    Value::Ident(v) => {
+                        if array_type.is_some() {
+                            error!(ErrorContext::InvalidType);
+                        }
+                        match self.named_type(v) {
+                            Some(t) => {
+                                array_type = Some(t);
+                            }
+                            None => {
+                                error!(ErrorContext::UnknownType(v.to_string()));
+                            }
+                        }
+                    }
                     _ => {
                         error!(ErrorContext::InvalidSyntaxInBlock("array".to_string()));
                     }
@@ -113,33 +154,33 @@ impl Parser {
             }
         }
 
-        if let Some(err) = parser_error {
-            Err(err)
+        if !errors.is_empty() {
+            Err(errors)
         } else if let Some(t) = array_type {
             Ok(Type::Array(Box::from(t)))
         } else {
             if let Some(position) = last_position {
                 if !block_closed {
-                    return Err(EnvlError {
+                    return Err(vec![EnvlError {
                         message: ErrorContext::IsntClosed("Array".to_string()),
                         position,
-                    });
+                    }]);
                 } else {
-                    return Err(EnvlError {
+                    return Err(vec![EnvlError {
                         message: ErrorContext::InvalidType,
                         position: position.clone(),
-                    });
+                    }]);
                 }
             }
 
-            Err(EnvlError {
+            Err(vec![EnvlError {
                 message: ErrorContext::InvalidType,
                 position: Position {
                     file_path: self.file_path.to_owned(),
                     col: 0,
                     row: 0,
                 },
-            })
+            }])
         }
     }
 }
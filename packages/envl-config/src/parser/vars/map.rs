@@ -0,0 +1,171 @@
+use std::slice::Iter;
+
+use envl_utils::{
+    error::{EnvlError, ErrorContext},
+    types::Position,
+};
+
+use crate::{
+    misc::{
+        token::{Token, Value},
+        variable::Type,
+    },
+    parser::Parser,
+};
+
+impl Parser {
+    pub fn parse_map<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<Type, Vec<EnvlError>> {
+        let mut in_block = false;
+        let mut block_closed = false;
+        let mut comma_used = false;
+        let mut last_position = None;
+        let mut key_type = None;
+        let mut value_type = None;
+
+        let mut errors = Vec::new();
+
+        'parse_loop: loop {
+            if let Some(token) = tokens.next() {
+                macro_rules! error {
+                    ($msg: expr) => {
+                        errors.push(EnvlError {
+                            message: $msg,
+                            position: token.position.clone(),
+                        });
+                        break 'parse_loop;
+                    };
+                }
+                macro_rules! set_value_type {
+                    ($value: expr) => {
+                        if key_type.is_none() || !comma_used || value_type.is_some() {
+                            error!(ErrorContext::InvalidType);
+                        }
+                        value_type = Some($value);
+                    };
+                }
+
+                last_position = Some(token.position.clone());
+
+                match &token.value {
+                    Value::LeftShift => {
+                        if in_block {
+                            error!(ErrorContext::InvalidPosition("<".to_string()));
+                        }
+                        in_block = true;
+                        continue;
+                    }
+                    Value::RightShift => {
+                        block_closed = true;
+                        break 'parse_loop;
+                    }
+                    _ => {}
+                }
+
+                if !in_block {
+                    error!(ErrorContext::InvalidSyntaxInBlock("vars".to_string()));
+                }
+
+                match &token.value {
+                    Value::Comma => {
+                        if comma_used || key_type.is_none() {
+                            error!(ErrorContext::InvalidPosition("Comma".to_string()));
+                        }
+                        comma_used = true;
+                    }
+                    Value::Type(t) if key_type.is_none() => {
+                        if *t != Type::String {
+                            error!(ErrorContext::InvalidType);
+                        }
+                        key_type = Some(t.to_owned());
+                    }
+                    Value::Type(t) => {
+                        set_value_type!(t.to_owned());
+                    }
+                    Value::Ident(v) => match self.named_type(v) {
+                        Some(t) => {
+                            set_value_type!(t);
+                        }
+                        None => {
+                            error!(ErrorContext::UnknownType(v.to_string()));
+                        }
+                    },
+                    Value::Option => match self.parse_option(tokens) {
+                        Ok(v) => {
+                            set_value_type!(v);
+                        }
+                        Err(errs) => {
+                            errors.extend(errs);
+                            break 'parse_loop;
+                        }
+                    },
+                    Value::Array => match self.parse_array(tokens) {
+                        Ok(v) => {
+                            set_value_type!(v);
+                        }
+                        Err(errs) => {
+                            errors.extend(errs);
+                            break 'parse_loop;
+                        }
+                    },
+                    Value::Map => match self.parse_map(tokens) {
+                        Ok(v) => {
+                            set_value_type!(v);
+                        }
+                        Err(errs) => {
+                            errors.extend(errs);
+                            break 'parse_loop;
+                        }
+                    },
+                    Value::Struct => match self.parse_struct(tokens) {
+                        Ok(v) => {
+                            set_value_type!(v);
+                        }
+                        Err(errs) => {
+                            errors.extend(errs);
+                            break 'parse_loop;
+                        }
+                    },
+                    Value::Enum => match self.parse_enum(tokens) {
+                        Ok(v) => {
+                            set_value_type!(v);
+                        }
+                        Err(err) => {
+                            errors.push(err);
+                            break 'parse_loop;
+                        }
+                    },
+                    _ => {
+                        error!(ErrorContext::InvalidSyntaxInBlock("map".to_string()));
+                    }
+                }
+            } else {
+                break 'parse_loop;
+            }
+        }
+
+        if !errors.is_empty() {
+            Err(errors)
+        } else if let (true, Some(t)) = (block_closed, value_type) {
+            Ok(Type::Map(Box::from(t)))
+        } else {
+            match last_position {
+                Some(position) if !block_closed => Err(vec![EnvlError {
+                    message: ErrorContext::IsntClosed("Map".to_string()),
+                    position,
+                }]),
+                Some(position) => Err(vec![EnvlError {
+                    message: ErrorContext::InvalidType,
+                    position,
+                }]),
+                None => Err(vec![EnvlError {
+                    message: ErrorContext::InvalidType,
+                    position: Position {
+                        file_path: self.file_path.to_owned(),
+                        col: 0,
+                        row: 0,
+                    },
+                }]),
+            }
+        }
+    }
+}
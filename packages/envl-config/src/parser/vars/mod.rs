@@ -7,40 +7,68 @@ use envl_utils::{
 
 use crate::{
     misc::{
-        config::{Var, Vars},
+        config::{Constraints, Var, Vars},
         token::{Token, Value},
         variable::{Type, Value as VarValue},
     },
-    parser::{vars::option_value::parse_parsed_value, Parser},
+    parser::{skip_element, vars::option_value::parse_option, Parser},
 };
 
 pub mod array;
+pub mod map;
 pub mod option;
 pub mod option_value;
+pub mod parse_actions;
+pub mod parse_enum;
 pub mod parse_struct;
 
 impl Parser {
-    pub fn parse_vars<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<Vars, EnvlError> {
+    pub fn parse_vars<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<Vars, Vec<EnvlError>> {
         let mut in_block = false;
         let mut block_closed = false;
         let mut colon_used = false;
         let mut comma_used = false;
-        let mut element_name = None;
+        let mut element_name: Option<String> = None;
         let mut inserted_element_name = None;
         let mut last_position = None;
 
-        let mut parser_error: Option<EnvlError> = None;
+        let mut errors = Vec::new();
         let mut vars = HashMap::new();
+        let mut element_start = tokens.clone();
 
         'parse_loop: loop {
+            if element_name.is_none() && !colon_used {
+                element_start = tokens.clone();
+            }
+
             if let Some(token) = tokens.next() {
+                // Skips to the next element of the block and keeps parsing from there
+                macro_rules! recover {
+                    ($errs: expr) => {
+                        errors.extend($errs);
+                        if !in_block {
+                            break 'parse_loop;
+                        }
+
+                        *tokens = element_start.clone();
+                        element_name = None;
+                        inserted_element_name = None;
+                        colon_used = false;
+
+                        if skip_element(tokens, &Value::Comma, &Value::RightCurlyBracket) {
+                            block_closed = true;
+                            break 'parse_loop;
+                        }
+                        comma_used = true;
+                        continue 'parse_loop;
+                    };
+                }
                 macro_rules! error {
                     ($msg: expr) => {
-                        parser_error = Some(EnvlError {
+                        recover!([EnvlError {
                             message: $msg,
                             position: token.position.clone(),
-                        });
-                        break 'parse_loop;
+                        }]);
                     };
                 }
                 macro_rules! insert {
@@ -96,6 +124,28 @@ impl Parser {
                         }
                         colon_used = true;
                     }
+                    Value::Ident(v) if element_name.is_some() && colon_used => {
+                        match self.named_type(v) {
+                            Some(t) => {
+                                if let Some(name) = element_name {
+                                    insert!(
+                                        name,
+                                        Var {
+                                            v_type: t,
+                                            default_value: VarValue::Null,
+                                            actions: Vec::new(),
+                                            env: None,
+                                            constraints: Constraints::default(),
+                                            position: token.position.to_owned()
+                                        }
+                                    );
+                                }
+                            }
+                            None => {
+                                error!(ErrorContext::UnknownType(v.to_string()));
+                            }
+                        }
+                    }
                     Value::Ident(v) => {
                         if element_name.is_some() {
                             error!(ErrorContext::InvalidElements);
@@ -112,7 +162,9 @@ impl Parser {
                                 Var {
                                     v_type: Type::Null,
                                     default_value: VarValue::Null,
-                                    actions_value: VarValue::Null,
+                                    actions: Vec::new(),
+                                    env: None,
+                                    constraints: Constraints::default(),
                                     position: token.position.to_owned()
                                 }
                             );
@@ -128,7 +180,9 @@ impl Parser {
                                     Var {
                                         v_type: t.clone(),
                                         default_value: VarValue::Null,
-                                        actions_value: VarValue::Null,
+                                        actions: Vec::new(),
+                                        env: None,
+                                        constraints: Constraints::default(),
                                         position: token.position.to_owned()
                                     }
                                 );
@@ -136,9 +190,8 @@ impl Parser {
                                 error!(ErrorContext::Required("Element name".to_string()));
                             }
                         }
-                        Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_loop;
+                        Err(errs) => {
+                            recover!(errs);
                         }
                     },
                     Value::Array => match self.parse_array(tokens) {
@@ -149,7 +202,9 @@ impl Parser {
                                     Var {
                                         v_type: t.clone(),
                                         default_value: VarValue::Null,
-                                        actions_value: VarValue::Null,
+                                        actions: Vec::new(),
+                                        env: None,
+                                        constraints: Constraints::default(),
                                         position: token.position.to_owned()
                                     }
                                 );
@@ -157,9 +212,30 @@ impl Parser {
                                 error!(ErrorContext::Required("Element name".to_string()));
                             }
                         }
-                        Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_loop;
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::Map => match self.parse_map(tokens) {
+                        Ok(t) => {
+                            if let Some(name) = element_name {
+                                insert!(
+                                    name,
+                                    Var {
+                                        v_type: t.clone(),
+                                        default_value: VarValue::Null,
+                                        actions: Vec::new(),
+                                        env: None,
+                                        constraints: Constraints::default(),
+                                        position: token.position.to_owned()
+                                    }
+                                );
+                            } else {
+                                error!(ErrorContext::Required("Element name".to_string()));
+                            }
+                        }
+                        Err(errs) => {
+                            recover!(errs);
                         }
                     },
                     Value::Struct => match self.parse_struct(tokens) {
@@ -170,7 +246,31 @@ impl Parser {
                                     Var {
                                         v_type: t.clone(),
                                         default_value: VarValue::Null,
-                                        actions_value: VarValue::Null,
+                                        actions: Vec::new(),
+                                        env: None,
+                                        constraints: Constraints::default(),
+                                        position: token.position.to_owned()
+                                    }
+                                );
+                            } else {
+                                error!(ErrorContext::Required("Element name".to_string()));
+                            }
+                        }
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::Enum => match self.parse_enum(tokens) {
+                        Ok(t) => {
+                            if let Some(name) = element_name {
+                                insert!(
+                                    name,
+                                    Var {
+                                        v_type: t.clone(),
+                                        default_value: VarValue::Null,
+                                        actions: Vec::new(),
+                                        env: None,
+                                        constraints: Constraints::default(),
                                         position: token.position.to_owned()
                                     }
                                 );
@@ -179,8 +279,7 @@ impl Parser {
                             }
                         }
                         Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_loop;
+                            recover!([err]);
                         }
                     },
                     Value::Type(t) => {
@@ -190,7 +289,9 @@ impl Parser {
                                 Var {
                                     v_type: t.clone(),
                                     default_value: VarValue::Null,
-                                    actions_value: VarValue::Null,
+                                    actions: Vec::new(),
+                                    env: None,
+                                    constraints: Constraints::default(),
                                     position: token.position.to_owned()
                                 }
                             );
@@ -199,40 +300,29 @@ impl Parser {
                         }
                     }
                     Value::LeftParentheses => match self.parse_option_value(tokens) {
-                        Ok((default_value, actions_value)) => {
+                        Ok(option_value) => {
                             if let Some(ref name) = inserted_element_name {
                                 if let Some(var) = vars.get(name) {
-                                    match parse_parsed_value(
-                                        default_value,
+                                    match parse_option(
+                                        option_value,
                                         var.v_type.clone(),
                                         token.position.clone(),
                                     ) {
-                                        Ok(parsed_default_value) => {
-                                            match parse_parsed_value(
-                                                actions_value,
-                                                var.v_type.clone(),
-                                                token.position.clone(),
-                                            ) {
-                                                Ok(parsed_actions_value) => {
-                                                    vars.insert(
-                                                        name.clone(),
-                                                        Var {
-                                                            v_type: var.v_type.clone(),
-                                                            default_value: parsed_default_value,
-                                                            actions_value: parsed_actions_value,
-                                                            position: var.position.clone(),
-                                                        },
-                                                    );
-                                                }
-                                                Err(err) => {
-                                                    parser_error = Some(err);
-                                                    break 'parse_loop;
-                                                }
-                                            }
+                                        Ok((default_value, actions, env, constraints)) => {
+                                            vars.insert(
+                                                name.clone(),
+                                                Var {
+                                                    v_type: var.v_type.clone(),
+                                                    default_value,
+                                                    actions,
+                                                    env,
+                                                    constraints,
+                                                    position: var.position.clone(),
+                                                },
+                                            );
                                         }
                                         Err(err) => {
-                                            parser_error = Some(err);
-                                            break 'parse_loop;
+                                            recover!([err]);
                                         }
                                     }
                                 } else {
@@ -242,9 +332,8 @@ impl Parser {
                                 error!(ErrorContext::Required("Element name".to_string()));
                             }
                         }
-                        Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_loop;
+                        Err(errs) => {
+                            recover!(errs);
                         }
                     },
                     _ => {
@@ -256,19 +345,19 @@ impl Parser {
             }
         }
 
-        if let Some(err) = parser_error {
-            Err(err)
-        } else {
-            if let Some(position) = last_position {
-                if !block_closed {
-                    return Err(EnvlError {
-                        message: ErrorContext::IsntClosed("vars".to_string()),
-                        position,
-                    });
-                }
+        if let Some(position) = last_position {
+            if !block_closed && (in_block || errors.is_empty()) {
+                errors.push(EnvlError {
+                    message: ErrorContext::IsntClosed("vars".to_string()),
+                    position,
+                });
             }
+        }
 
+        if errors.is_empty() {
             Ok(vars)
+        } else {
+            Err(errors)
         }
     }
 }
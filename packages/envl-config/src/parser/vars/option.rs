@@ -14,19 +14,19 @@ use crate::{
 };
 
 impl Parser {
-    pub fn parse_option<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<Type, EnvlError> {
+    pub fn parse_option<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<Type, Vec<EnvlError>> {
         let mut in_block = false;
         let mut block_closed = false;
         let mut last_position = None;
         let mut optional_type = None;
 
-        let mut parser_error = None;
+        let mut errors = Vec::new();
 
         'parse_loop: loop {
             if let Some(token) = tokens.next() {
                 macro_rules! error {
                     ($msg: expr) => {
-                        parser_error = Some(EnvlError {
+                        errors.push(EnvlError {
                             message: $msg,
                             position: token.position.clone(),
                         });
@@ -62,21 +62,47 @@ impl Parser {
                     Value::Type(t) => {
                         optional_type = Some(t.clone());
                     }
+                    Value::Ident(v) => match self.named_type(v) {
+                        Some(t) => {
+                            optional_type = Some(t);
+                        }
+                        None => {
+                            error!(ErrorContext::UnknownType(v.to_string()));
+                        }
+                    },
                     Value::Array => match self.parse_array(tokens) {
                         Ok(t) => {
                             optional_type = Some(t);
                         }
-                        Err(err) => {
-                            parser_error = Some(err);
+                        Err(errs) => {
+                            errors.extend(errs);
                             break 'parse_loop;
                         }
                     },
                     Value::Struct => match self.parse_struct(tokens) {
+                        Ok(t) => {
+                            optional_type = Some(t);
+                        }
+                        Err(errs) => {
+                            errors.extend(errs);
+                            break 'parse_loop;
+                        }
+                    },
+                    Value::Map => match self.parse_map(tokens) {
+                        Ok(t) => {
+                            optional_type = Some(t);
+                        }
+                        Err(errs) => {
+                            errors.extend(errs);
+                            break 'parse_loop;
+                        }
+                    },
+                    Value::Enum => match self.parse_enum(tokens) {
                         Ok(t) => {
                             optional_type = Some(t);
                         }
                         Err(err) => {
-                            parser_error = Some(err);
+                            errors.push(err);
                             break 'parse_loop;
                         }
                     },
@@ -89,28 +115,28 @@ impl Parser {
             }
         }
 
-        if let Some(err) = parser_error {
-            Err(err)
+        if !errors.is_empty() {
+            Err(errors)
         } else {
             if let Some(position) = last_position {
                 if !block_closed {
-                    return Err(EnvlError {
+                    return Err(vec![EnvlError {
                         message: ErrorContext::IsntClosed("Option".to_string()),
                         position,
-                    });
+                    }]);
                 }
             }
             if let Some(t) = optional_type {
                 Ok(Type::Option(Box::from(t)))
             } else {
-                Err(EnvlError {
+                Err(vec![EnvlError {
                     message: ErrorContext::InvalidType,
                     position: Position {
                         file_path: self.file_path.to_owned(),
                         row: 0,
                         col: 0,
                     },
-                })
+                }])
             }
         }
     }
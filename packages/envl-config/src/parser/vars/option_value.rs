@@ -1,35 +1,60 @@
-use std::{collections::HashMap, slice::Iter};
+use std::{
+    collections::{BTreeMap, HashMap},
+    slice::Iter,
+};
 
 use envl_utils::{
     error::{EnvlError, ErrorContext},
     types::Position,
 };
+use regex::Regex;
 
 use crate::{
     misc::{
+        config::{Action, Constraints},
         token::{Token, Value},
         variable::{Type, Value as ConfigValue},
     },
     parser::{
+        skip_element,
         value::parse_value,
-        var::{array::parse_array, parse_struct::parse_struct},
+        var::{array::parse_array, map::parse_map, parse_struct::parse_struct},
         Parser,
     },
 };
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub enum ParsedValue {
     Array(Vec<ParsedValue>),
     Struct(HashMap<String, ParsedValue>),
+    Map(BTreeMap<String, ParsedValue>),
     Value(String),
+    #[default]
     Null,
 }
 
+#[derive(Debug, Clone, Default)]
+pub struct ParsedOptionValue {
+    pub default_value: ParsedValue,
+    pub actions: Vec<Action>,
+    pub env: ParsedValue,
+    pub min: ParsedValue,
+    pub max: ParsedValue,
+    pub min_len: ParsedValue,
+    pub max_len: ParsedValue,
+    pub pattern: ParsedValue,
+    pub one_of: ParsedValue,
+}
+
 pub fn parse_parsed_value(
     v: ParsedValue,
     t: Type,
     position: Position,
 ) -> Result<ConfigValue, EnvlError> {
+    if let Type::Named(_, t) | Type::Option(t) = t {
+        return parse_parsed_value(v, *t, position);
+    }
+
     match v {
         ParsedValue::Null => Ok(ConfigValue::Null),
         ParsedValue::Value(value) => match parse_value(t, value) {
@@ -69,6 +94,40 @@ pub fn parse_parsed_value(
 
                 Ok(ConfigValue::Struct(elements))
             }
+            Type::Map(t) => {
+                let mut elements = BTreeMap::new();
+
+                for (name, value) in values {
+                    match parse_parsed_value(value, *t.to_owned(), position.clone()) {
+                        Ok(result) => {
+                            elements.insert(name, result);
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                Ok(ConfigValue::Map(elements))
+            }
+            _ => Err(EnvlError {
+                message: ErrorContext::InvalidType,
+                position,
+            }),
+        },
+        ParsedValue::Map(values) => match t {
+            Type::Map(t) => {
+                let mut elements = BTreeMap::new();
+
+                for (key, value) in values {
+                    elements.insert(
+                        key,
+                        parse_parsed_value(value, *t.to_owned(), position.clone())?,
+                    );
+                }
+
+                Ok(ConfigValue::Map(elements))
+            }
             _ => Err(EnvlError {
                 message: ErrorContext::InvalidType,
                 position,
@@ -100,11 +159,107 @@ pub fn parse_parsed_value(
     }
 }
 
+pub fn parse_option(
+    option_value: ParsedOptionValue,
+    t: Type,
+    position: Position,
+) -> Result<(ConfigValue, Vec<Action>, Option<String>, Constraints), EnvlError> {
+    let default_value =
+        parse_parsed_value(option_value.default_value, t.clone(), position.clone())?;
+    let env = match parse_parsed_value(option_value.env, Type::String, position.clone())? {
+        ConfigValue::String(name) => Some(name),
+        _ => None,
+    };
+    let element_type = match t {
+        Type::Option(boxed_type) => *boxed_type,
+        t => t,
+    };
+    let element_type = match element_type {
+        Type::Named(_, boxed_type) => *boxed_type,
+        t => t,
+    };
+
+    macro_rules! check_type {
+        ($name: literal, $($pattern: pat_param)|+) => {
+            if !matches!(element_type, $($pattern)|+) {
+                return Err(EnvlError {
+                    message: ErrorContext::InvalidProperty($name.to_string()),
+                    position,
+                });
+            }
+        };
+    }
+
+    let mut constraints = Constraints::default();
+
+    if !matches!(option_value.min, ParsedValue::Null) {
+        check_type!("min", Type::Int | Type::Uint | Type::Float);
+        constraints.min = Some(parse_parsed_value(
+            option_value.min,
+            element_type.clone(),
+            position.clone(),
+        )?);
+    }
+    if !matches!(option_value.max, ParsedValue::Null) {
+        check_type!("max", Type::Int | Type::Uint | Type::Float);
+        constraints.max = Some(parse_parsed_value(
+            option_value.max,
+            element_type.clone(),
+            position.clone(),
+        )?);
+    }
+    if !matches!(option_value.min_len, ParsedValue::Null) {
+        check_type!("min_len", Type::String | Type::Array(_) | Type::Map(_));
+        if let ConfigValue::Uint(n) =
+            parse_parsed_value(option_value.min_len, Type::Uint, position.clone())?
+        {
+            constraints.min_len = Some(n);
+        }
+    }
+    if !matches!(option_value.max_len, ParsedValue::Null) {
+        check_type!("max_len", Type::String | Type::Array(_) | Type::Map(_));
+        if let ConfigValue::Uint(n) =
+            parse_parsed_value(option_value.max_len, Type::Uint, position.clone())?
+        {
+            constraints.max_len = Some(n);
+        }
+    }
+    if !matches!(option_value.pattern, ParsedValue::Null) {
+        check_type!("pattern", Type::String);
+        if let ConfigValue::String(s) =
+            parse_parsed_value(option_value.pattern, Type::String, position.clone())?
+        {
+            if Regex::new(&s).is_err() {
+                return Err(EnvlError {
+                    message: ErrorContext::InvalidPattern(s),
+                    position,
+                });
+            }
+            constraints.pattern = Some(s);
+        }
+    }
+    if !matches!(option_value.one_of, ParsedValue::Null) {
+        check_type!(
+            "one_of",
+            Type::String | Type::Char | Type::Int | Type::Uint | Type::Float | Type::Enum(_)
+        );
+        if let ConfigValue::Array(values) = parse_parsed_value(
+            option_value.one_of,
+            Type::Array(Box::from(element_type.clone())),
+            position.clone(),
+        )? {
+            constraints.one_of = Some(values);
+        }
+    }
+
+    Ok((default_value, option_value.actions, env, constraints))
+}
+
 impl Parser {
     pub fn parse_option_value<'a>(
         &self,
         tokens: &mut Iter<'a, Token>,
-    ) -> Result<(ParsedValue, ParsedValue), EnvlError> {
+    ) -> Result<ParsedOptionValue, Vec<EnvlError>> {
         let mut block_closed = false;
         let mut comma_used = false;
         let mut colon_used = false;
@@ -112,22 +267,56 @@ impl Parser {
         let mut last_position = None;
         let mut inserted_count = 0;
 
-        let mut default_value = ParsedValue::Null;
-        let mut actions_value = ParsedValue::Null;
-        let mut parser_error = None;
+        let mut option_value = ParsedOptionValue::default();
+        let mut errors = Vec::new();
+        let mut element_start = tokens.clone();
 
         'parse_loop: loop {
+            if element_name.is_none() && !colon_used {
+                element_start = tokens.clone();
+            }
+
             if let Some(token) = tokens.next() {
+                // Skips to the next option of the block and keeps parsing from there
+                macro_rules! recover {
+                    ($err: expr) => {
+                        errors.push($err);
+
+                        *tokens = element_start.clone();
+                        element_name = None;
+                        colon_used = false;
+
+                        if skip_element(tokens, &Value::Comma, &Value::RightParentheses) {
+                            block_closed = true;
+                            break 'parse_loop;
+                        }
+                        comma_used = true;
+                        continue 'parse_loop;
+                    };
+                }
                 macro_rules! error {
                     ($msg: expr) => {
-                        parser_error = Some(EnvlError {
+                        recover!(EnvlError {
                             message: $msg,
                             position: token.position.clone(),
                         });
-                        break 'parse_loop;
                     };
                 }
                 macro_rules! insert {
+                    (actions: $value: expr) => {
+                        if !colon_used {
+                            error!(ErrorContext::Required("Colon".to_string()));
+                        }
+                        if !comma_used && inserted_count != 0 {
+                            error!(ErrorContext::Required("Comma".to_string()));
+                        } else {
+                            comma_used = false;
+                        }
+                        option_value.actions = $value;
+                        element_name = None;
+                        inserted_count += 1;
+                        colon_used = false;
+                    };
                     ($value: expr) => {
                         if !colon_used {
                             error!(ErrorContext::Required("Colon".to_string()));
@@ -137,14 +326,25 @@ impl Parser {
                         } else {
                             comma_used = false;
                         }
-                        match &element_name {
-                            Some(v) if v == "default" => {
-                                default_value = $value;
-                            }
-                            Some(v) if v == "actions" => {
-                                actions_value = $value;
+                        if element_name.as_deref() == Some("actions") {
+                            error!(ErrorContext::InvalidProperty("actions".to_string()));
+                        }
+                        let target = match element_name.as_deref() {
+                            Some("default") => Some(&mut option_value.default_value),
+                            Some("env") => Some(&mut option_value.env),
+                            Some("min") => Some(&mut option_value.min),
+                            Some("max") => Some(&mut option_value.max),
+                            Some("min_len") => Some(&mut option_value.min_len),
+                            Some("max_len") => Some(&mut option_value.max_len),
+                            Some("pattern") => Some(&mut option_value.pattern),
+                            Some("one_of") => Some(&mut option_value.one_of),
+                            _ => None,
+                        };
+                        match target {
+                            Some(target) => {
+                                *target = $value;
                             }
-                            _ => {
+                            None => {
                                 error!(ErrorContext::InvalidSyntaxInBlock(
                                     "option value".to_string()
                                 ));
@@ -178,6 +378,16 @@ impl Parser {
                         }
                         comma_used = true;
                     }
+                    Value::Ident(v) if v == "map" && element_name.is_some() => {
+                        match parse_map(tokens) {
+                            Ok(v) => {
+                                insert!(v);
+                            }
+                            Err(err) => {
+                                recover!(err);
+                            }
+                        }
+                    }
                     Value::Ident(v) => {
                         if element_name.is_some() {
                             insert!(ParsedValue::Value(v.clone()));
@@ -197,17 +407,25 @@ impl Parser {
                             insert!(v);
                         }
                         Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_loop;
+                            recover!(err);
                         }
                     },
+                    Value::LeftSquareBracket if element_name.as_deref() == Some("actions") => {
+                        match self.parse_actions(tokens) {
+                            Ok(v) => {
+                                insert!(actions: v);
+                            }
+                            Err(err) => {
+                                recover!(err);
+                            }
+                        }
+                    }
                     Value::LeftSquareBracket => match parse_array(tokens) {
                         Ok(v) => {
                             insert!(v);
                         }
                         Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_loop;
+                            recover!(err);
                         }
                     },
                     _ => {
@@ -221,19 +439,19 @@ impl Parser {
             }
         }
 
-        if let Some(err) = parser_error {
-            Err(err)
-        } else {
-            if let Some(position) = last_position {
-                if !block_closed {
-                    return Err(EnvlError {
-                        message: ErrorContext::IsntClosed("option".to_string()),
-                        position,
-                    });
-                }
+        if let Some(position) = last_position {
+            if !block_closed {
+                errors.push(EnvlError {
+                    message: ErrorContext::IsntClosed("option".to_string()),
+                    position,
+                });
             }
+        }
 
-            Ok((default_value, actions_value))
+        if errors.is_empty() {
+            Ok(option_value)
+        } else {
+            Err(errors)
         }
     }
 }
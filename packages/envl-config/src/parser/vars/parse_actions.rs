@@ -0,0 +1,152 @@
+use std::slice::Iter;
+
+use envl_utils::{
+    error::{EnvlError, ErrorContext},
+    types::Position,
+};
+
+use crate::{
+    misc::{
+        config::Action,
+        token::{Token, Value},
+        variable::{Type, Value as ConfigValue},
+    },
+    parser::{value::parse_value, Parser},
+};
+
+fn to_action(
+    name: String,
+    argument: Option<String>,
+    position: Position,
+) -> Result<Action, EnvlError> {
+    let action = match (name.as_str(), argument) {
+        ("trim", None) => Action::Trim,
+        ("lowercase", None) => Action::Lowercase,
+        ("uppercase", None) => Action::Uppercase,
+        ("expand_home", None) => Action::ExpandHome,
+        ("split", Some(separator)) => Action::Split(separator),
+        ("split", None) => {
+            return Err(EnvlError {
+                message: ErrorContext::Required("Separator".to_string()),
+                position,
+            });
+        }
+        _ => {
+            return Err(EnvlError {
+                message: ErrorContext::InvalidAction(name),
+                position,
+            });
+        }
+    };
+
+    Ok(action)
+}
+
+impl Parser {
+    pub fn parse_actions<'a>(
+        &self,
+        tokens: &mut Iter<'a, Token>,
+    ) -> Result<Vec<Action>, EnvlError> {
+        let mut block_closed = false;
+        let mut comma_used = false;
+        let mut in_arguments = false;
+        let mut last_position = None;
+        let mut entries: Vec<(String, Option<String>, Position)> = Vec::new();
+
+        let mut parser_error = None;
+
+        'parse_loop: loop {
+            if let Some(token) = tokens.next() {
+                macro_rules! error {
+                    ($msg: expr) => {
+                        parser_error = Some(EnvlError {
+                            message: $msg,
+                            position: token.position.clone(),
+                        });
+                        break 'parse_loop;
+                    };
+                }
+
+                last_position = Some(token.position.to_owned());
+
+                match &token.value {
+                    Value::RightSquareBracket => {
+                        if in_arguments {
+                            error!(ErrorContext::IsntClosed("(".to_string()));
+                        }
+                        block_closed = true;
+                        break 'parse_loop;
+                    }
+                    Value::Comma => {
+                        if in_arguments || comma_used || entries.is_empty() {
+                            error!(ErrorContext::InvalidPosition("Comma".to_string()));
+                        }
+                        comma_used = true;
+                    }
+                    Value::LeftParentheses => match entries.last() {
+                        Some((_, None, _)) if !in_arguments && !comma_used => {
+                            in_arguments = true;
+                        }
+                        _ => {
+                            error!(ErrorContext::InvalidPosition("(".to_string()));
+                        }
+                    },
+                    Value::RightParentheses => {
+                        if !in_arguments {
+                            error!(ErrorContext::InvalidPosition(")".to_string()));
+                        }
+                        if let Some((_, None, _)) = entries.last() {
+                            error!(ErrorContext::Required("Argument".to_string()));
+                        }
+                        in_arguments = false;
+                    }
+                    Value::Ident(v) => {
+                        if in_arguments {
+                            if let Some((_, argument @ None, _)) = entries.last_mut() {
+                                match parse_value(Type::String, v.to_owned()) {
+                                    Ok(ConfigValue::String(s)) => {
+                                        *argument = Some(s);
+                                    }
+                                    _ => {
+                                        error!(ErrorContext::InvalidType);
+                                    }
+                                }
+                            } else {
+                                error!(ErrorContext::InvalidSyntaxInBlock("actions".to_string()));
+                            }
+                        } else {
+                            if !entries.is_empty() && !comma_used {
+                                error!(ErrorContext::Required("Comma".to_string()));
+                            }
+                            entries.push((v.to_owned(), None, token.position.to_owned()));
+                            comma_used = false;
+                        }
+                    }
+                    _ => {
+                        error!(ErrorContext::InvalidSyntaxInBlock("actions".to_string()));
+                    }
+                }
+            } else {
+                break 'parse_loop;
+            }
+        }
+
+        if let Some(err) = parser_error {
+            Err(err)
+        } else {
+            if let Some(position) = last_position {
+                if !block_closed {
+                    return Err(EnvlError {
+                        message: ErrorContext::IsntClosed("actions".to_string()),
+                        position,
+                    });
+                }
+            }
+
+            entries
+                .into_iter()
+                .map(|(name, argument, position)| to_action(name, argument, position))
+                .collect()
+        }
+    }
+}
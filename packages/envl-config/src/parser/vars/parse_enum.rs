@@ -0,0 +1,109 @@
+use std::slice::Iter;
+
+use envl_utils::{
+    error::{EnvlError, ErrorContext},
+    name::is_valid_variant_name,
+};
+
+use crate::{
+    misc::{
+        token::{Token, Value},
+        variable::Type,
+    },
+    parser::Parser,
+};
+
+impl Parser {
+    pub fn parse_enum<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<Type, EnvlError> {
+        let mut in_block = false;
+        let mut block_closed = false;
+        let mut comma_used = false;
+        let mut last_position = None;
+        let mut variants: Vec<String> = Vec::new();
+
+        let mut parser_error = None;
+
+        'parse_loop: loop {
+            if let Some(token) = tokens.next() {
+                macro_rules! error {
+                    ($msg: expr) => {
+                        parser_error = Some(EnvlError {
+                            message: $msg,
+                            position: token.position.clone(),
+                        });
+                        break 'parse_loop;
+                    };
+                }
+
+                last_position = Some(token.position.to_owned());
+
+                match &token.value {
+                    Value::LeftCurlyBracket => {
+                        if in_block {
+                            error!(ErrorContext::InvalidPosition("{".to_string()));
+                        }
+                        in_block = true;
+                        continue;
+                    }
+                    Value::RightCurlyBracket => {
+                        block_closed = true;
+                        break 'parse_loop;
+                    }
+                    _ => {}
+                }
+
+                if !in_block {
+                    error!(ErrorContext::MustInBlock("vars".to_string()));
+                }
+
+                match &token.value {
+                    Value::Comma => {
+                        if comma_used || variants.is_empty() {
+                            error!(ErrorContext::InvalidPosition("Comma".to_string()));
+                        }
+                        comma_used = true;
+                    }
+                    Value::Ident(v) => {
+                        if !variants.is_empty() && !comma_used {
+                            error!(ErrorContext::Required("Comma".to_string()));
+                        }
+                        if !is_valid_variant_name(v) {
+                            error!(ErrorContext::InvalidName(v.to_string()));
+                        }
+                        if variants.contains(v) {
+                            error!(ErrorContext::Duplicate(v.to_string()));
+                        }
+                        variants.push(v.to_owned());
+                        comma_used = false;
+                    }
+                    _ => {
+                        error!(ErrorContext::InvalidSyntaxInBlock("enum".to_string()));
+                    }
+                }
+            } else {
+                break 'parse_loop;
+            }
+        }
+
+        if let Some(err) = parser_error {
+            Err(err)
+        } else {
+            if let Some(position) = last_position {
+                if !block_closed {
+                    return Err(EnvlError {
+                        message: ErrorContext::IsntClosed("enum".to_string()),
+                        position,
+                    });
+                }
+                if variants.is_empty() {
+                    return Err(EnvlError {
+                        message: ErrorContext::Required("Variant".to_string()),
+                        position,
+                    });
+                }
+            }
+
+            Ok(Type::Enum(variants))
+        }
+    }
+}
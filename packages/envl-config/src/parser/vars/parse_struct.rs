@@ -10,11 +10,11 @@ use crate::{
         token::{Token, Value},
         variable::Type,
     },
-    parser::Parser,
+    parser::{skip_element, Parser},
 };
 
 impl Parser {
-    pub fn parse_struct<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<Type, EnvlError> {
+    pub fn parse_struct<'a>(&self, tokens: &mut Iter<'a, Token>) -> Result<Type, Vec<EnvlError>> {
         let mut in_block = false;
         let mut block_closed = false;
         let mut colon_used = false;
@@ -23,17 +23,41 @@ impl Parser {
         let mut target_value = None;
         let mut elements = HashMap::new();
 
-        let mut parser_error = None;
+        let mut errors = Vec::new();
+        let mut element_start = tokens.clone();
 
         'parse_loop: loop {
+            if target_prop.is_none() && !colon_used {
+                element_start = tokens.clone();
+            }
+
             if let Some(token) = tokens.next() {
+                // Skips to the next element of the block and keeps parsing from there
+                macro_rules! recover {
+                    ($errs: expr) => {
+                        errors.extend($errs);
+                        if !in_block {
+                            break 'parse_loop;
+                        }
+
+                        *tokens = element_start.clone();
+                        target_prop = None;
+                        target_value = None;
+                        colon_used = false;
+
+                        if skip_element(tokens, &Value::Semi, &Value::RightCurlyBracket) {
+                            block_closed = true;
+                            break 'parse_loop;
+                        }
+                        continue 'parse_loop;
+                    };
+                }
                 macro_rules! error {
                     ($msg: expr) => {
-                        parser_error = Some(EnvlError {
+                        recover!([EnvlError {
                             message: $msg,
                             position: token.position.clone(),
-                        });
-                        break 'parse_loop;
+                        }]);
                     };
                 }
                 macro_rules! insert {
@@ -102,6 +126,16 @@ impl Parser {
                             error!(ErrorContext::InvalidSyntaxInBlock("struct".to_string()));
                         }
                     },
+                    Value::Ident(v) if target_prop.is_some() && colon_used => {
+                        match self.named_type(v) {
+                            Some(t) => {
+                                insert_target_value!(t);
+                            }
+                            None => {
+                                error!(ErrorContext::UnknownType(v.to_string()));
+                            }
+                        }
+                    }
                     Value::Ident(v) => {
                         if target_prop.is_some() {
                             error!(ErrorContext::InvalidElements);
@@ -118,27 +152,40 @@ impl Parser {
                         Ok(v) => {
                             insert_target_value!(v);
                         }
-                        Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_loop;
+                        Err(errs) => {
+                            recover!(errs);
                         }
                     },
                     Value::Array => match self.parse_array(tokens) {
                         Ok(v) => {
                             insert_target_value!(v);
                         }
-                        Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_loop;
+                        Err(errs) => {
+                            recover!(errs);
                         }
                     },
                     Value::Struct => match self.parse_struct(tokens) {
+                        Ok(t) => {
+                            insert_target_value!(t);
+                        }
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::Map => match self.parse_map(tokens) {
+                        Ok(v) => {
+                            insert_target_value!(v);
+                        }
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::Enum => match self.parse_enum(tokens) {
                         Ok(t) => {
                             insert_target_value!(t);
                         }
                         Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_loop;
+                            recover!([err]);
                         }
                     },
                     _ => {
@@ -150,19 +197,19 @@ impl Parser {
             }
         }
 
-        if let Some(err) = parser_error {
-            Err(err)
-        } else {
-            if let Some(position) = last_position {
-                if !block_closed {
-                    return Err(EnvlError {
-                        message: ErrorContext::IsntClosed("struct".to_string()),
-                        position,
-                    });
-                }
+        if let Some(position) = last_position {
+            if !block_closed && (in_block || errors.is_empty()) {
+                errors.push(EnvlError {
+                    message: ErrorContext::IsntClosed("struct".to_string()),
+                    position,
+                });
             }
+        }
 
+        if errors.is_empty() {
             Ok(Type::Struct(elements))
+        } else {
+            Err(errors)
         }
     }
 }
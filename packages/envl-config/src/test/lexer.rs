@@ -8,6 +8,7 @@ mod lexer_test {
     fn generate_tokens(code: String) -> Vec<Value> {
         let lex = Lexer::new("test.envl".to_string(), code);
         lex.generate()
+            .unwrap()
             .into_iter()
             .map(|t| t.value)
             .collect::<Vec<_>>()
@@ -170,10 +171,6 @@ mod lexer_test {
                 Value::Ident("default".to_string()),
                 Value::Colon,
                 Value::Ident("123".to_string()),
-                Value::Comma,
-                Value::Ident("actions".to_string()),
-                Value::Colon,
-                Value::Ident("456".to_string()),
                 Value::RightParentheses,
                 Value::Comma,
                 Value::Ident("b".to_string()),
@@ -183,10 +180,6 @@ mod lexer_test {
                 Value::Ident("default".to_string()),
                 Value::Colon,
                 Value::Ident("false".to_string()),
-                Value::Comma,
-                Value::Ident("actions".to_string()),
-                Value::Colon,
-                Value::Ident("true".to_string()),
                 Value::RightParentheses,
                 Value::Comma,
                 Value::Ident("c".to_string()),
@@ -196,10 +189,6 @@ mod lexer_test {
                 Value::Ident("default".to_string()),
                 Value::Colon,
                 Value::Null,
-                Value::Comma,
-                Value::Ident("actions".to_string()),
-                Value::Colon,
-                Value::Null,
                 Value::RightParentheses,
                 Value::Comma,
                 Value::Ident("d".to_string()),
@@ -216,11 +205,6 @@ mod lexer_test {
                 Value::Comma,
                 Value::Ident("456".to_string()),
                 Value::RightSquareBracket,
-                Value::Comma,
-                Value::Ident("actions".to_string()),
-                Value::Colon,
-                Value::LeftSquareBracket,
-                Value::RightSquareBracket,
                 Value::RightParentheses,
                 Value::Comma,
                 Value::Ident("e".to_string()),
@@ -244,13 +228,6 @@ mod lexer_test {
                 Value::Ident("456".to_string()),
                 Value::RightSquareBracket,
                 Value::RightSquareBracket,
-                Value::Comma,
-                Value::Ident("actions".to_string()),
-                Value::Colon,
-                Value::LeftSquareBracket,
-                Value::LeftSquareBracket,
-                Value::RightSquareBracket,
-                Value::RightSquareBracket,
                 Value::RightParentheses,
                 Value::Comma,
                 Value::Ident("f".to_string()),
@@ -272,16 +249,6 @@ mod lexer_test {
                 Value::Ident("true".to_string()),
                 Value::Semi,
                 Value::RightCurlyBracket,
-                Value::Comma,
-                Value::Ident("actions".to_string()),
-                Value::Colon,
-                Value::Struct,
-                Value::LeftCurlyBracket,
-                Value::Ident("a".to_string()),
-                Value::Colon,
-                Value::Ident("false".to_string()),
-                Value::Semi,
-                Value::RightCurlyBracket,
                 Value::RightParentheses,
                 Value::Comma,
                 Value::Ident("g".to_string()),
@@ -315,22 +282,6 @@ mod lexer_test {
                 Value::RightCurlyBracket,
                 Value::Semi,
                 Value::RightCurlyBracket,
-                Value::Comma,
-                Value::Ident("actions".to_string()),
-                Value::Colon,
-                Value::Struct,
-                Value::LeftCurlyBracket,
-                Value::Ident("a".to_string()),
-                Value::Colon,
-                Value::Struct,
-                Value::LeftCurlyBracket,
-                Value::Ident("b".to_string()),
-                Value::Colon,
-                Value::Ident("false".to_string()),
-                Value::Semi,
-                Value::RightCurlyBracket,
-                Value::Semi,
-                Value::RightCurlyBracket,
                 Value::RightParentheses,
                 Value::RightCurlyBracket,
             ]
@@ -381,11 +332,6 @@ mod lexer_test {
                 Value::Semi,
                 Value::RightCurlyBracket,
                 Value::RightSquareBracket,
-                Value::Comma,
-                Value::Ident("actions".to_string()),
-                Value::Colon,
-                Value::LeftSquareBracket,
-                Value::RightSquareBracket,
                 Value::RightParentheses,
                 Value::Comma,
                 Value::Ident("b".to_string()),
@@ -414,17 +360,6 @@ mod lexer_test {
                 Value::RightSquareBracket,
                 Value::Semi,
                 Value::RightCurlyBracket,
-                Value::Comma,
-                Value::Ident("actions".to_string()),
-                Value::Colon,
-                Value::Struct,
-                Value::LeftCurlyBracket,
-                Value::Ident("a".to_string()),
-                Value::Colon,
-                Value::LeftSquareBracket,
-                Value::RightSquareBracket,
-                Value::Semi,
-                Value::RightCurlyBracket,
                 Value::RightParentheses,
                 Value::RightCurlyBracket,
             ]
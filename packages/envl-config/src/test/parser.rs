@@ -1,24 +1,28 @@
 #[cfg(test)]
 pub mod parser_test {
-    use std::collections::HashMap;
+    use std::{
+        collections::{BTreeMap, HashMap},
+        env::temp_dir,
+        fs::{create_dir_all, write},
+    };
 
-    use envl_utils::error::EnvlError;
+    use envl_utils::error::{EnvlError, ErrorContext};
 
     use crate::{
         lexer::Lexer,
         misc::{
             config::{
-                remove_position_prop, Config, ConfigWithoutPosition, SettingWithoutPotision,
-                SettingsWithoutPosition, VarWithoutPosition,
+                remove_position_prop, Action, Config, ConfigWithoutPosition, Constraints,
+                SettingWithoutPotision, SettingsWithoutPosition, VarWithoutPosition,
             },
             variable::{Type, Value},
         },
         parser::Parser,
     };
 
-    fn gen_obj(code: String) -> Result<Config, EnvlError> {
+    fn gen_obj(code: String) -> Result<Config, Vec<EnvlError>> {
         let lex = Lexer::new("test.envl".to_string(), code);
-        let tokens = lex.generate();
+        let tokens = lex.generate()?;
         let parser = Parser::new("test.envl".to_string(), tokens);
         parser.parse()
     }
@@ -72,7 +76,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::String,
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -80,7 +86,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Char,
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -88,7 +96,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Float,
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -96,7 +106,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Int,
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -104,7 +116,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Uint,
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -112,7 +126,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Bool,
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -120,7 +136,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Array(Box::from(Type::Int)),
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -131,7 +149,9 @@ pub mod parser_test {
                                 ("b".to_string(), Type::Int)
                             ])),
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -139,7 +159,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Null,
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -149,7 +171,9 @@ pub mod parser_test {
                                 HashMap::from([("a".to_string(), Type::Int)])
                             ))))),
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -163,7 +187,9 @@ pub mod parser_test {
                                 ("b".to_string(), Type::Array(Box::from(Type::Int)))
                             ])),
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     )
                 ])
@@ -186,7 +212,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Int,
                             default_value: Value::Int(123),
-                            actions_value: Value::Int(456)
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -194,7 +222,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Bool,
                             default_value: Value::Bool(false),
-                            actions_value: Value::Bool(true)
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -202,7 +232,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::String,
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -210,7 +242,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Array(Box::from(Type::Int)),
                             default_value: Value::Array(vec![Value::Int(123), Value::Int(456)]),
-                            actions_value: Value::Array(vec![])
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -221,7 +255,9 @@ pub mod parser_test {
                                 Value::Array(vec![Value::Int(123)]),
                                 Value::Array(vec![Value::Int(456)])
                             ]),
-                            actions_value: Value::Array(vec![Value::Array(vec![])])
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -232,10 +268,9 @@ pub mod parser_test {
                                 "a".to_string(),
                                 Value::Bool(true)
                             )])),
-                            actions_value: Value::Struct(HashMap::from([(
-                                "a".to_string(),
-                                Value::Bool(false)
-                            )]))
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -252,13 +287,9 @@ pub mod parser_test {
                                     Value::Bool(true)
                                 )]))
                             )])),
-                            actions_value: Value::Struct(HashMap::from([(
-                                "a".to_string(),
-                                Value::Struct(HashMap::from([(
-                                    "b".to_string(),
-                                    Value::Bool(false)
-                                )]))
-                            )]))
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     )
                 ])
@@ -266,6 +297,22 @@ pub mod parser_test {
         );
     }
 
+    #[test]
+    fn struct_default_test() {
+        let config = gen_parsed_obj(
+            "settings {} vars { a: struct { x: int; y: bool; z: string; } ( default: struct { x: 1; y: true; z: \"z\"; } ) }"
+                .to_string(),
+        );
+        assert_eq!(
+            config.vars.get("a").unwrap().default_value,
+            Value::Struct(HashMap::from([
+                ("x".to_string(), Value::Int(1)),
+                ("y".to_string(), Value::Bool(true)),
+                ("z".to_string(), Value::String("z".to_string())),
+            ]))
+        );
+    }
+
     #[test]
     fn uncommon_option_value_test() {
         let config =
@@ -288,7 +335,9 @@ pub mod parser_test {
                                 Value::Struct(HashMap::from([("a".to_string(), Value::Int(123))])),
                                 Value::Struct(HashMap::from([("a".to_string(), Value::Int(456))]))
                             ]),
-                            actions_value: Value::Array(vec![])
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -302,10 +351,9 @@ pub mod parser_test {
                                 "a".to_string(),
                                 Value::Array(vec![Value::Int(123), Value::Int(456)])
                             )])),
-                            actions_value: Value::Struct(HashMap::from([(
-                                "a".to_string(),
-                                Value::Array(vec![])
-                            )]))
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     )
                 ])
@@ -328,7 +376,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Option(Box::from(Type::Bool)),
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -336,7 +386,9 @@ pub mod parser_test {
                         VarWithoutPosition {
                             v_type: Type::Option(Box::from(Type::Array(Box::from(Type::Int)))),
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -347,7 +399,9 @@ pub mod parser_test {
                                 ("b".to_string(), Type::Option(Box::from(Type::Int)))
                             ])))),
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     ),
                     (
@@ -357,11 +411,576 @@ pub mod parser_test {
                                 Box::from(Type::String)
                             ))))),
                             default_value: Value::Null,
-                            actions_value: Value::Null
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
+                        }
+                    )
+                ])
+            }
+        )
+    }
+
+    #[test]
+    fn enum_test() {
+        let config = gen_parsed_obj(include_str!("./files/enum.test.envl").to_string());
+        assert_eq!(
+            config,
+            ConfigWithoutPosition {
+                settings: SettingsWithoutPosition {
+                    envl_file_path: None
+                },
+                vars: HashMap::from([
+                    (
+                        "a".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::Enum(vec![
+                                "Debug".to_string(),
+                                "Info".to_string(),
+                                "Warn".to_string()
+                            ]),
+                            default_value: Value::String("Info".to_string()),
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
+                        }
+                    ),
+                    (
+                        "b".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::Array(Box::from(Type::Enum(vec![
+                                "Tokyo".to_string(),
+                                "Osaka".to_string()
+                            ]))),
+                            default_value: Value::Null,
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
+                        }
+                    ),
+                    (
+                        "c".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::Struct(HashMap::from([(
+                                "mode".to_string(),
+                                Type::Option(Box::from(Type::Enum(vec![
+                                    "Fast".to_string(),
+                                    "Slow".to_string()
+                                ])))
+                            )])),
+                            default_value: Value::Null,
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
+                        }
+                    )
+                ])
+            }
+        )
+    }
+
+    #[test]
+    fn invalid_enum_default_test() {
+        let result = gen_obj(
+            "settings {}\nvars {\n    a: enum { Debug, Info } (\n        default: \"Trace\"\n    )\n}"
+                .to_string(),
+        );
+        assert_eq!(
+            result.unwrap_err()[0].message,
+            ErrorContext::InvalidVariant("Trace".to_string())
+        );
+    }
+
+    #[test]
+    fn map_test() {
+        let config = gen_parsed_obj(include_str!("./files/map.test.envl").to_string());
+        assert_eq!(
+            config,
+            ConfigWithoutPosition {
+                settings: SettingsWithoutPosition {
+                    envl_file_path: None
+                },
+                vars: HashMap::from([
+                    (
+                        "a".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::Map(Box::from(Type::Int)),
+                            default_value: Value::Map(BTreeMap::from([
+                                ("tenant-a".to_string(), Value::Int(1)),
+                                ("a.b".to_string(), Value::Int(2))
+                            ])),
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
+                        }
+                    ),
+                    (
+                        "b".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::Map(Box::from(Type::Array(Box::from(Type::Struct(
+                                HashMap::from([("c".to_string(), Type::Bool)])
+                            ))))),
+                            default_value: Value::Null,
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
+                        }
+                    ),
+                    (
+                        "c".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::Option(Box::from(Type::Map(Box::from(Type::Map(
+                                Box::from(Type::String)
+                            ))))),
+                            default_value: Value::Map(BTreeMap::from([(
+                                "x".to_string(),
+                                Value::Map(BTreeMap::from([(
+                                    "y".to_string(),
+                                    Value::String("z".to_string())
+                                )]))
+                            )])),
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints::default()
                         }
                     )
                 ])
             }
         )
     }
+
+    #[test]
+    fn map_default_test() {
+        let config = gen_parsed_obj(
+            "settings {} vars { a: Map<string, int> ( default: struct { x: 1; } ) }".to_string(),
+        );
+        assert_eq!(
+            config.vars.get("a").unwrap().default_value,
+            Value::Map(BTreeMap::from([("x".to_string(), Value::Int(1))]))
+        );
+
+        for code in [
+            "a: struct { x: int; } ( default: map { \"x\": 1 } )",
+            "a: Map<string, int> ( default: map { \"x\": \"y\" } )",
+        ] {
+            let result = gen_obj(format!("settings {{}} vars {{ {} }}", code));
+            assert_eq!(result.unwrap_err()[0].message, ErrorContext::InvalidType);
+        }
+
+        let result = gen_obj(
+            "settings {} vars { a: Map<string, int> ( default: map { x: 1 } ) }".to_string(),
+        );
+        assert_eq!(
+            result.unwrap_err()[0].message,
+            ErrorContext::InvalidName("x".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_map_key_test() {
+        let result = gen_obj("settings {} vars { a: Map<int, string> }".to_string());
+        assert_eq!(result.unwrap_err()[0].message, ErrorContext::InvalidType);
+    }
+
+    #[test]
+    fn constraints_test() {
+        let config = gen_parsed_obj(include_str!("./files/constraints.test.envl").to_string());
+        assert_eq!(
+            config,
+            ConfigWithoutPosition {
+                settings: SettingsWithoutPosition {
+                    envl_file_path: None
+                },
+                vars: HashMap::from([
+                    (
+                        "port".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::Uint,
+                            default_value: Value::Uint(8080),
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints {
+                                min: Some(Value::Uint(1)),
+                                max: Some(Value::Uint(65535)),
+                                ..Default::default()
+                            }
+                        }
+                    ),
+                    (
+                        "url".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::String,
+                            default_value: Value::Null,
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints {
+                                min_len: Some(1),
+                                max_len: Some(2048),
+                                pattern: Some("^https?://".to_string()),
+                                ..Default::default()
+                            }
+                        }
+                    ),
+                    (
+                        "level".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::Option(Box::from(Type::String)),
+                            default_value: Value::Null,
+                            actions: Vec::new(),
+                            env: None,
+                            constraints: Constraints {
+                                one_of: Some(vec![
+                                    Value::String("debug".to_string()),
+                                    Value::String("info".to_string())
+                                ]),
+                                ..Default::default()
+                            }
+                        }
+                    )
+                ])
+            }
+        )
+    }
+
+    #[test]
+    fn invalid_constraint_test() {
+        let result = gen_obj("settings {} vars { a: bool ( min: 1 ) }".to_string());
+        assert_eq!(
+            result.unwrap_err()[0].message,
+            ErrorContext::InvalidProperty("min".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_pattern_test() {
+        let result =
+            gen_obj("settings {}\nvars {\n    a: string ( pattern: \"(\" )\n}".to_string());
+        let err = &result.unwrap_err()[0];
+        assert_eq!(err.message, ErrorContext::InvalidPattern("(".to_string()));
+        assert_eq!((err.position.row, err.position.col), (3, 15));
+    }
+
+    #[test]
+    fn actions_test() {
+        let config = gen_parsed_obj(include_str!("./files/actions.test.envl").to_string());
+        assert_eq!(
+            config,
+            ConfigWithoutPosition {
+                settings: SettingsWithoutPosition {
+                    envl_file_path: None
+                },
+                vars: HashMap::from([
+                    (
+                        "a".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::String,
+                            default_value: Value::Null,
+                            actions: vec![Action::Trim, Action::Lowercase],
+                            env: None,
+                            constraints: Constraints::default()
+                        }
+                    ),
+                    (
+                        "b".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::Array(Box::from(Type::String)),
+                            default_value: Value::Null,
+                            actions: vec![
+                                Action::Split(",".to_string()),
+                                Action::Trim,
+                                Action::Uppercase
+                            ],
+                            env: None,
+                            constraints: Constraints::default()
+                        }
+                    ),
+                    (
+                        "c".to_string(),
+                        VarWithoutPosition {
+                            v_type: Type::String,
+                            default_value: Value::String("~/.cache".to_string()),
+                            actions: vec![Action::ExpandHome],
+                            env: None,
+                            constraints: Constraints::default()
+                        }
+                    )
+                ])
+            }
+        )
+    }
+
+    #[test]
+    fn invalid_action_test() {
+        let result = gen_obj("settings {} vars { a: string ( actions: [ reverse ] ) }".to_string());
+        assert_eq!(
+            result.unwrap_err()[0].message,
+            ErrorContext::InvalidAction("reverse".to_string())
+        );
+
+        let result = gen_obj("settings {} vars { a: string ( actions: [ split ] ) }".to_string());
+        assert_eq!(
+            result.unwrap_err()[0].message,
+            ErrorContext::Required("Separator".to_string())
+        );
+    }
+
+    #[test]
+    fn env_test() {
+        let config =
+            gen_parsed_obj("settings {} vars { a: string ( env: \"DATABASE_URL\" ) }".to_string());
+        assert_eq!(
+            config.vars.get("a").unwrap().env,
+            Some("DATABASE_URL".to_string())
+        );
+
+        let result = gen_obj("settings {} vars { a: string ( env: 123 ) }".to_string());
+        assert_eq!(result.unwrap_err()[0].message, ErrorContext::InvalidType);
+    }
+
+    fn gen_imported_obj(
+        dir_name: &str,
+        files: Vec<(&str, &str)>,
+    ) -> Result<Config, Vec<EnvlError>> {
+        let dir = temp_dir().join(dir_name);
+        create_dir_all(&dir).unwrap();
+        for (name, code) in files.iter() {
+            write(dir.join(name), code).unwrap();
+        }
+        let file_path = dir.join(files[0].0).display().to_string();
+        let lex = Lexer::new(file_path.to_owned(), files[0].1.to_string());
+        let parser = Parser::new(file_path, lex.generate()?);
+        parser.parse()
+    }
+
+    #[test]
+    fn import_test() {
+        let config = gen_imported_obj(
+            "envl_config_import_test",
+            vec![
+                (
+                    ".envlconf",
+                    "import \"database.envlconf\";\nimport \"telemetry.envlconf\";\nsettings {}\nvars {\n    a: int\n}",
+                ),
+                (
+                    "database.envlconf",
+                    "import \"common.envlconf\";\nvars {\n    db: struct {\n        host: string;\n    }\n}",
+                ),
+                (
+                    "telemetry.envlconf",
+                    "import \"common.envlconf\";\nvars {\n    telemetry: bool\n}",
+                ),
+                ("common.envlconf", "vars {\n    service: string\n}"),
+            ],
+        )
+        .unwrap();
+
+        let mut names = config.vars.keys().cloned().collect::<Vec<_>>();
+        names.sort();
+        assert_eq!(names, vec!["a", "db", "service", "telemetry"]);
+        assert!(config
+            .vars
+            .get("db")
+            .unwrap()
+            .position
+            .file_path
+            .ends_with("database.envlconf"));
+    }
+
+    #[test]
+    fn import_error_test() {
+        let result = gen_imported_obj(
+            "envl_config_import_conflict_test",
+            vec![
+                (
+                    ".envlconf",
+                    "import \"common.envlconf\";\nsettings {}\nvars {\n    a: int\n}",
+                ),
+                ("common.envlconf", "vars {\n    a: string\n}"),
+            ],
+        );
+        assert!(matches!(
+            &result.unwrap_err()[0].message,
+            ErrorContext::DuplicateAt(name, _) if name == "a"
+        ));
+
+        let result = gen_imported_obj(
+            "envl_config_import_cycle_test",
+            vec![
+                (".envlconf", "import \"a.envlconf\";\nsettings {}\nvars {}"),
+                ("a.envlconf", "import \".envlconf\";\nvars {}"),
+            ],
+        );
+        assert_eq!(
+            result.unwrap_err()[0].message,
+            ErrorContext::IncludeCycle(".envlconf".to_string())
+        );
+    }
+
+    #[test]
+    fn types_test() {
+        let config = gen_parsed_obj(include_str!("./files/types.test.envl").to_string());
+        let database = Type::Named(
+            "Database".to_string(),
+            Box::from(Type::Struct(HashMap::from([
+                ("host".to_string(), Type::String),
+                ("port".to_string(), Type::Uint),
+            ]))),
+        );
+        let cluster = Type::Named(
+            "Cluster".to_string(),
+            Box::from(Type::Struct(HashMap::from([
+                ("primary".to_string(), database.to_owned()),
+                (
+                    "replicas".to_string(),
+                    Type::Array(Box::from(database.to_owned())),
+                ),
+            ]))),
+        );
+
+        assert_eq!(config.vars.get("a").unwrap().v_type, database);
+        assert_eq!(
+            config.vars.get("b").unwrap().v_type,
+            Type::Array(Box::from(database.to_owned()))
+        );
+        assert_eq!(
+            config.vars.get("c").unwrap().v_type,
+            Type::Option(Box::from(database.to_owned()))
+        );
+        assert_eq!(config.vars.get("d").unwrap().v_type, cluster);
+        assert_eq!(
+            config.vars.get("e").unwrap().default_value,
+            Value::String("Info".to_string())
+        );
+    }
+
+    #[test]
+    fn unknown_type_test() {
+        let result = gen_obj("settings {} vars { a: Database }".to_string());
+        assert_eq!(
+            result.unwrap_err()[0].message,
+            ErrorContext::UnknownType("Database".to_string())
+        );
+    }
+
+    #[test]
+    fn types_after_vars_test() {
+        let config = gen_parsed_obj(
+            "settings {} vars { a: Level } types { Level: enum { Low, High } }".to_string(),
+        );
+        assert_eq!(
+            config.vars.get("a").unwrap().v_type,
+            Type::Named(
+                "Level".to_string(),
+                Box::from(Type::Enum(vec!["Low".to_string(), "High".to_string()]))
+            )
+        );
+    }
+
+    #[test]
+    fn invalid_variant_name_test() {
+        for name in ["1st", "_", "type", "self"] {
+            let result = gen_obj(format!(
+                "settings {{}} vars {{ a: enum {{ Low, {} }} }}",
+                name
+            ));
+            assert_eq!(
+                result.unwrap_err()[0].message,
+                ErrorContext::InvalidName(name.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn reserved_type_name_test() {
+        for (code, name) in [
+            ("types { Env: struct { a: int; } }", "Env"),
+            ("types { String: int }", "String"),
+            ("types { int: bool }", "int"),
+            ("types { Array: bool }", "Array"),
+        ] {
+            let result = gen_obj(format!("settings {{}} {} vars {{}}", code));
+            assert_eq!(
+                result.unwrap_err()[0].message,
+                ErrorContext::InvalidName(name.to_string())
+            );
+        }
+    }
+
+    #[test]
+    fn strings_test() {
+        let config = gen_parsed_obj(include_str!("./files/strings.test.envl").to_string());
+        let defaults = ["a", "b", "c", "d"]
+            .iter()
+            .map(|name| config.vars.get(*name).unwrap().default_value.to_owned())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            defaults,
+            vec![
+                Value::String(
+                    "-----BEGIN CERTIFICATE-----\nMIIB\n-----END CERTIFICATE-----".to_string()
+                ),
+                Value::String("{\"level\": \"info\"}".to_string()),
+                Value::String("café".to_string()),
+                Value::Char('あ'),
+            ]
+        );
+    }
+
+    #[test]
+    fn error_recovery_test() {
+        let result = gen_obj(
+            "settings {\n    envl_file_path: 1\n}\nvars {\n    a: int,\n    b: Database,\n    c: struct {\n        x: ???;\n    },\n    d: int ( default: \"x\" ),\n    a: string,\n    e: bool\n}"
+                .to_string(),
+        );
+        let errors = result
+            .unwrap_err()
+            .iter()
+            .map(|err| (err.message.to_owned(), err.position.row))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    ErrorContext::InvalidSyntaxInBlock("settings".to_string()),
+                    2
+                ),
+                (ErrorContext::UnknownType("Database".to_string()), 6),
+                (ErrorContext::UnknownType("???".to_string()), 8),
+                (ErrorContext::InvalidType, 10),
+                (ErrorContext::Duplicate("a".to_string()), 11),
+            ]
+        );
+    }
+
+    #[test]
+    fn block_error_recovery_test() {
+        let result = gen_obj(
+            "settings {\n    foo = \"a\";\n    envl_file_path = 1;\n    envl_file_path = \"./.envl\";\n}\ntypes {\n    A: Unknown,\n    B: struct {\n        x: ???;\n        y: Missing;\n        z: int;\n    },\n    C: int\n}\nvars {\n    a: C (\n        bogus: 2,\n        default: 1,\n        other: 3\n    ),\n    b: int,\n    c: Unknown\n}"
+                .to_string(),
+        );
+        let errors = result
+            .unwrap_err()
+            .iter()
+            .map(|err| (err.message.to_owned(), err.position.row))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                (ErrorContext::UnknownType("Unknown".to_string()), 7),
+                (ErrorContext::UnknownType("???".to_string()), 9),
+                (ErrorContext::UnknownType("Missing".to_string()), 10),
+                (ErrorContext::InvalidProperty("settings".to_string()), 2),
+                (ErrorContext::InvalidType, 3),
+                (
+                    ErrorContext::InvalidSyntaxInBlock("option value".to_string()),
+                    17
+                ),
+                (
+                    ErrorContext::InvalidSyntaxInBlock("option value".to_string()),
+                    19
+                ),
+                (ErrorContext::UnknownType("Unknown".to_string()), 22),
+            ]
+        );
+    }
 }
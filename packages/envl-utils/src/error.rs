@@ -32,9 +32,15 @@ pub enum ErrorContext {
     #[error("Invalid Type")]
     InvalidType,
 
+    #[error("{0} isn't a valid number")]
+    InvalidNumber(String),
+
     #[error("{0} isn't closed")]
     IsntClosed(String),
 
+    #[error("{0} isn't a valid escape")]
+    InvalidEscape(String),
+
     #[error("Write {0} after the equal written")]
     AfterEqual(String),
 
@@ -53,6 +59,9 @@ pub enum ErrorContext {
     #[error("{0} is duplicated")]
     Duplicate(String),
 
+    #[error("{0} is duplicated (first defined at {1})")]
+    DuplicateAt(String, Position),
+
     #[error("Can't use this syntax outside of the vars and settings blocks")]
     InvalidSettingsSyntax,
 
@@ -73,4 +82,25 @@ pub enum ErrorContext {
 
     #[error("Invalid variable name {0}")]
     InvalidName(String),
+
+    #[error("{0} isn't a variant of the enum")]
+    InvalidVariant(String),
+
+    #[error("{0} isn't a valid action")]
+    InvalidAction(String),
+
+    #[error("{0} isn't a valid pattern")]
+    InvalidPattern(String),
+
+    #[error("Can't read the included file {0}")]
+    IncludeNotFound(String),
+
+    #[error("{0} is outside the project root")]
+    OutsideRoot(String),
+
+    #[error("{0} is included recursively")]
+    IncludeCycle(String),
+
+    #[error("{0} isn't a defined type")]
+    UnknownType(String),
 }
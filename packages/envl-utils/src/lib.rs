@@ -1,4 +1,5 @@
 pub mod case;
 pub mod error;
 pub mod name;
+pub mod string;
 pub mod types;
@@ -8,9 +8,97 @@ pub fn is_valid_variable_name(name: &str) -> bool {
     }
 }
 
+/// Enum variants become identifiers in the generated code, so they have to
+/// start with a letter and can't be a Rust keyword
+pub fn is_valid_variant_name(name: &str) -> bool {
+    let valid = match RegexBuilder::new(r"^[A-Za-z][A-Za-z0-9_]*$").build() {
+        Ok(regex) => regex.is_match(name),
+        Err(_) => false,
+    };
+
+    valid
+        && !matches!(
+            name,
+            "as" | "async"
+                | "await"
+                | "break"
+                | "const"
+                | "continue"
+                | "crate"
+                | "dyn"
+                | "else"
+                | "enum"
+                | "extern"
+                | "false"
+                | "fn"
+                | "for"
+                | "gen"
+                | "if"
+                | "impl"
+                | "in"
+                | "let"
+                | "loop"
+                | "match"
+                | "mod"
+                | "move"
+                | "mut"
+                | "pub"
+                | "ref"
+                | "return"
+                | "self"
+                | "Self"
+                | "static"
+                | "struct"
+                | "super"
+                | "trait"
+                | "true"
+                | "type"
+                | "unsafe"
+                | "use"
+                | "where"
+                | "while"
+                | "abstract"
+                | "become"
+                | "box"
+                | "do"
+                | "final"
+                | "macro"
+                | "override"
+                | "priv"
+                | "try"
+                | "typeof"
+                | "unsized"
+                | "virtual"
+                | "yield"
+        )
+}
+
+/// Type names that the generated code already uses, such as the `Env`
+/// struct and the standard types it is built from
+pub fn is_reserved_type_name(name: &str) -> bool {
+    matches!(
+        name,
+        "Env"
+            | "String"
+            | "Vec"
+            | "Box"
+            | "HashMap"
+            | "BTreeMap"
+            | "Some"
+            | "None"
+            | "Result"
+            | "Ok"
+            | "Err"
+            | "Self"
+            | "Record"
+            | "Literal"
+            | "Optional"
+    )
+}
+
 #[cfg(test)]
 mod test {
-    use crate::name::is_valid_variable_name;
+    use crate::name::{is_reserved_type_name, is_valid_variable_name, is_valid_variant_name};
 
     #[test]
     fn variable_name_check() {
@@ -23,4 +111,24 @@ mod test {
         assert!(!is_valid_variable_name("abc?def"));
         assert!(!is_valid_variable_name("abc[]"));
     }
+
+    #[test]
+    fn variant_name_check() {
+        assert!(is_valid_variant_name("Debug"));
+        assert!(is_valid_variant_name("info_2"));
+
+        assert!(!is_valid_variant_name("1st"));
+        assert!(!is_valid_variant_name("_"));
+        assert!(!is_valid_variant_name("type"));
+        assert!(!is_valid_variant_name("self"));
+    }
+
+    #[test]
+    fn reserved_type_name_check() {
+        assert!(is_reserved_type_name("Env"));
+        assert!(is_reserved_type_name("String"));
+
+        assert!(!is_reserved_type_name("Database"));
+        assert!(!is_reserved_type_name("env"));
+    }
 }
@@ -0,0 +1,136 @@
+/// A `\u{...}` escape that doesn't name a character
+#[derive(Debug, PartialEq)]
+pub struct InvalidEscape {
+    /// Byte offset of the backslash in the input
+    pub offset: usize,
+    pub escape: String,
+}
+
+/// Replaces escape sequences such as `\n` and `\u{1F600}` with the
+/// characters they stand for. Unknown escapes keep only the escaped
+/// character, and a malformed `\u` escape is an error.
+pub fn unescape(value: &str) -> Result<String, InvalidEscape> {
+    let mut result = String::new();
+    let mut chars = value.char_indices();
+
+    while let Some((i, c)) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some((_, 'n')) => result.push('\n'),
+            Some((_, 't')) => result.push('\t'),
+            Some((_, 'r')) => result.push('\r'),
+            Some((_, '0')) => result.push('\0'),
+            Some((j, 'u')) => {
+                let escape = value[(j + 1)..]
+                    .strip_prefix('{')
+                    .and_then(|rest| rest.split_once('}'))
+                    .map(|(hex, _)| hex);
+                match escape.and_then(parse_unicode) {
+                    Some(unicode) => {
+                        result.push(unicode);
+                        let len = escape.map(|hex| hex.len()).unwrap_or_default() + 2;
+                        for _ in 0..len {
+                            chars.next();
+                        }
+                    }
+                    None => {
+                        let end = match escape {
+                            Some(hex) => j + hex.len() + 3,
+                            None => j + 1,
+                        };
+                        return Err(InvalidEscape {
+                            offset: i,
+                            escape: value[i..end].to_string(),
+                        });
+                    }
+                }
+            }
+            Some((_, other)) => result.push(other),
+            None => result.push(c),
+        }
+    }
+
+    Ok(result)
+}
+
+fn parse_unicode(hex: &str) -> Option<char> {
+    if hex.is_empty() || hex.len() > 6 {
+        return None;
+    }
+
+    u32::from_str_radix(hex, 16).ok().and_then(char::from_u32)
+}
+
+/// Removes the line breaks next to the delimiters of a multiline string
+/// and the indentation shared by all of its lines. The line of the closing
+/// delimiter counts towards the shared indentation.
+pub fn strip_indent(value: &str) -> String {
+    let mut lines = value.split('\n').collect::<Vec<_>>();
+
+    if lines.len() > 1 && lines[0].trim().is_empty() {
+        lines.remove(0);
+    }
+    let closing_line = match lines.last() {
+        Some(last) if lines.len() > 1 && last.trim().is_empty() => lines.pop(),
+        _ => None,
+    };
+
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .chain(closing_line.iter())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or_default();
+
+    lines
+        .iter()
+        .map(|line| {
+            let line_indent = line.len() - line.trim_start().len();
+            &line[indent.min(line_indent)..]
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::string::{strip_indent, unescape, InvalidEscape};
+
+    #[test]
+    fn unescape_test() {
+        assert_eq!(unescape("a\\nb\\tc"), Ok("a\nb\tc".to_string()));
+        assert_eq!(unescape("\\\"quoted\\\""), Ok("\"quoted\"".to_string()));
+        assert_eq!(unescape("\\u{48}\\u{1F600}"), Ok("H😀".to_string()));
+    }
+
+    #[test]
+    fn invalid_escape_test() {
+        for (value, offset, escape) in [
+            ("a\\u{zz}", 1, "\\u{zz}"),
+            ("\\u{110000}b", 0, "\\u{110000}"),
+            ("ab\\u48", 2, "\\u"),
+        ] {
+            assert_eq!(
+                unescape(value),
+                Err(InvalidEscape {
+                    offset,
+                    escape: escape.to_string()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn strip_indent_test() {
+        assert_eq!(strip_indent("\n    a\n      b\n    "), "a\n  b");
+        assert_eq!(strip_indent("\n    a\n\n    b\n    "), "a\n\nb");
+        assert_eq!(strip_indent("\n    a\n"), "    a");
+        assert_eq!(strip_indent("\n  a\n  "), "a");
+        assert_eq!(strip_indent("a\n  b"), "a\n  b");
+    }
+}
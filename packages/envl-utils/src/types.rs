@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::{collections::HashMap, fmt};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Value {
@@ -26,9 +26,38 @@ pub enum VarType {
     Struct(HashMap<String, VarType>),
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Position {
     pub file_path: String,
     pub row: usize,
     pub col: usize,
 }
+
+impl Position {
+    /// Position of the character that comes after this one and `text`
+    pub fn after(&self, text: &str) -> Position {
+        let mut row = self.row;
+        let mut col = self.col;
+
+        for c in text.chars() {
+            if c == '\n' {
+                row += 1;
+                col = 0;
+            } else {
+                col += 1;
+            }
+        }
+
+        Position {
+            file_path: self.file_path.clone(),
+            row,
+            col: col + 1,
+        }
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.file_path, self.row, self.col)
+    }
+}
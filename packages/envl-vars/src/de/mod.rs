@@ -0,0 +1,188 @@
+use std::vec::IntoIter;
+
+use envl_utils::types::Position;
+use serde::de::{
+    self,
+    value::{MapAccessDeserializer, MapDeserializer, SeqDeserializer, StringDeserializer},
+    DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, Visitor,
+};
+
+use crate::{generate_ast, misc::error::Error, misc::variable::VariableValue};
+
+pub fn from_str<T: DeserializeOwned>(code: &str) -> Result<T, Error> {
+    from_file_str("envl".to_string(), code)
+}
+
+pub fn from_file_str<T: DeserializeOwned>(file_path: String, code: &str) -> Result<T, Error> {
+    // serde errors carry a single message, so report the first problem
+    let vars = generate_ast(file_path, code.to_string())
+        .map_err(|mut errors| Error::from(errors.remove(0)))?;
+    let deserializer = VarsDeserializer {
+        vars: vars
+            .into_iter()
+            .map(|v| (v.name, v.value, v.position))
+            .collect::<Vec<_>>()
+            .into_iter(),
+        current: None,
+    };
+
+    T::deserialize(deserializer)
+}
+
+struct VarsDeserializer {
+    vars: IntoIter<(String, VariableValue, Position)>,
+    current: Option<(VariableValue, Position)>,
+}
+
+impl<'de> Deserializer<'de> for VarsDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> MapAccess<'de> for VarsDeserializer {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.vars.next() {
+            Some((name, value, position)) => {
+                self.current = Some((value, position.clone()));
+                seed.deserialize(StringDeserializer::<Error>::new(name))
+                    .map(Some)
+                    .map_err(|err| Error {
+                        position: Some(err.position.unwrap_or(position)),
+                        ..err
+                    })
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        match self.current.take() {
+            Some((value, position)) => {
+                seed.deserialize(ValueDeserializer(value))
+                    .map_err(|err| Error {
+                        position: Some(err.position.unwrap_or(position)),
+                        ..err
+                    })
+            }
+            None => Err(Error::new("Value is missing".to_string())),
+        }
+    }
+}
+
+struct ValueDeserializer(VariableValue);
+
+impl<'de> IntoDeserializer<'de, Error> for ValueDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            // `$${` is how a literal `${` is written outside raw strings
+            VariableValue::String(s) => visitor.visit_string(s.replace("$${", "${")),
+            VariableValue::RawString(s) => visitor.visit_string(s),
+            VariableValue::Char(c) => visitor.visit_char(c),
+            VariableValue::Bool(b) => visitor.visit_bool(b),
+            VariableValue::Null => visitor.visit_unit(),
+            VariableValue::Number(n) => {
+                if let Ok(i) = n.to_i64() {
+                    visitor.visit_i64(i)
+                } else if let Ok(u) = n.to_u64() {
+                    visitor.visit_u64(u)
+                } else {
+                    match n.to_f64() {
+                        Ok(f) => visitor.visit_f64(f),
+                        Err(err) => Err(Error::new(err)),
+                    }
+                }
+            }
+            VariableValue::Array(a) => {
+                let mut seq = SeqDeserializer::new(a.into_iter().map(ValueDeserializer));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            VariableValue::Struct(s) => {
+                let mut elements = s.into_iter().collect::<Vec<_>>();
+                elements.sort_by(|a, b| a.0.cmp(&b.0));
+                let mut map = MapDeserializer::new(
+                    elements.into_iter().map(|(n, v)| (n, ValueDeserializer(v))),
+                );
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            VariableValue::Map(m) => {
+                let mut map =
+                    MapDeserializer::new(m.into_iter().map(|(n, v)| (n, ValueDeserializer(v))));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            VariableValue::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.0 {
+            VariableValue::String(s) | VariableValue::RawString(s) => {
+                visitor.visit_enum(StringDeserializer::<Error>::new(s))
+            }
+            VariableValue::Struct(s) if s.len() == 1 => {
+                visitor.visit_enum(MapAccessDeserializer::new(MapDeserializer::new(
+                    s.into_iter().map(|(n, v)| (n, ValueDeserializer(v))),
+                )))
+            }
+            _ => Err(de::Error::custom(
+                "Enums must be written as a string or a struct with a single element",
+            )),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
+
+pub mod test;
@@ -0,0 +1,53 @@
+#[cfg(all(test, feature = "serde"))]
+mod de_test {
+    use serde::Deserialize;
+
+    use crate::from_str;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Server {
+        host: String,
+        port: u16,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Env {
+        server: Server,
+        debug: Option<bool>,
+    }
+
+    #[test]
+    fn from_str_test() {
+        let result = from_str::<Env>(
+            "server = struct {\n    host: \"localhost\",\n    port: 8080\n};"
+                .to_string()
+                .as_str(),
+        )
+        .unwrap();
+        assert_eq!(
+            result,
+            Env {
+                server: Server {
+                    host: "localhost".to_string(),
+                    port: 8080
+                },
+                debug: None
+            }
+        );
+    }
+
+    #[test]
+    fn type_error_test() {
+        let err = from_str::<Env>(
+            "debug = 1;\nserver = struct {\n    host: \"localhost\",\n    port: 80\n};",
+        )
+        .unwrap_err();
+        assert_eq!(err.position.map(|p| p.row), Some(1));
+    }
+
+    #[test]
+    fn syntax_error_test() {
+        let err = from_str::<Env>("server = ;").unwrap_err();
+        assert!(err.position.is_some());
+    }
+}
@@ -1,9 +1,15 @@
 use crate::{lexer::Lexer, misc::token::Value};
 
 impl Lexer {
-    pub fn lex_current_token(&self, token: String) -> Value {
+    /// `next` is the first character after the token, so `map` and `include`
+    /// can still be used as names in front of `=` and `:`
+    pub fn lex_current_token(&self, token: String, next: Option<char>) -> Value {
+        let is_name = matches!(next, Some('=' | ':'));
+
         match token.as_str() {
             "struct" => Value::Struct,
+            "map" if !is_name => Value::Map,
+            "include" if !is_name => Value::Include,
             other => Value::Ident(other.to_string()),
         }
     }
@@ -1,10 +1,21 @@
-use envl_utils::types::Position;
+use envl_utils::{
+    error::{EnvlError, ErrorContext},
+    string::{strip_indent, unescape},
+    types::Position,
+};
 
 use crate::misc::token::{Token, Value};
 
 pub mod current_token;
 pub mod test;
 
+#[derive(Clone, Copy, PartialEq)]
+enum StringKind {
+    Quoted,
+    Multiline,
+    Raw(usize),
+}
+
 pub struct Lexer {
     file_path: String,
     code: String,
@@ -15,7 +26,7 @@ impl Lexer {
         Self { file_path, code }
     }
 
-    pub fn generate(&self) -> Vec<Token> {
+    pub fn generate(&self) -> Result<Vec<Token>, Vec<EnvlError>> {
         let mut tokens: Vec<Token> = Vec::new();
         let mut row = 1;
         let mut col = 0;
@@ -23,10 +34,15 @@ impl Lexer {
         let mut is_comment = false;
         let mut is_escape = false;
         let mut start_quote = char::default();
+        let mut string_kind = StringKind::Quoted;
         let mut current_token = String::new();
+        let mut token_start = None;
+        let mut errors = Vec::new();
+        let mut chars = self.code.char_indices();
 
-        'lexer_loop: for (i, c) in self.code.char_indices() {
+        'lexer_loop: while let Some((i, c)) = chars.next() {
             let is_last = self.code.len() == (i + 1);
+            let rest = &self.code[(i + c.len_utf8())..];
             let mut is_others = false;
 
             if is_comment && (c == '\n' || is_last) {
@@ -46,6 +62,10 @@ impl Lexer {
             }
 
             if c == '\n' {
+                if in_quote {
+                    current_token.push(c);
+                    is_escape = false;
+                }
                 row += 1;
                 col = 0;
                 continue;
@@ -59,45 +79,95 @@ impl Lexer {
                 col,
             };
 
-            if is_escape {
-                current_token.push(match c {
-                    'n' => '\n',
-                    't' => '\t',
-                    'r' => '\r',
-                    '0' => '\0',
-                    _ => c,
+            if in_quote {
+                let closing = match string_kind {
+                    StringKind::Quoted => !is_escape && c == start_quote,
+                    StringKind::Multiline => !is_escape && c == '"' && rest.starts_with("\"\""),
+                    StringKind::Raw(hashes) => c == '"' && rest.starts_with(&"#".repeat(hashes)),
+                };
+                if !closing {
+                    is_escape =
+                        !is_escape && c == '\\' && !matches!(string_kind, StringKind::Raw(_));
+                    current_token.push(c);
+                    continue;
+                }
+
+                let start = token_start.take().unwrap_or(position);
+                let value = match string_kind {
+                    StringKind::Raw(_) => current_token.clone(),
+                    // Escapes are checked before the indentation is stripped
+                    // so that errors point into the source
+                    _ => match unescape(&current_token) {
+                        Ok(value) if string_kind == StringKind::Quoted => value,
+                        Ok(_) => unescape(&strip_indent(&current_token)).unwrap_or_default(),
+                        Err(err) => {
+                            let opening = match string_kind {
+                                StringKind::Multiline => "\"\"",
+                                _ => "",
+                            };
+                            errors.push(EnvlError {
+                                message: ErrorContext::InvalidEscape(err.escape),
+                                position: start.after(&format!(
+                                    "{}{}",
+                                    opening,
+                                    &current_token[..err.offset]
+                                )),
+                            });
+                            String::new()
+                        }
+                    },
+                };
+                let delimiter_len = match string_kind {
+                    StringKind::Quoted => 0,
+                    StringKind::Multiline => 2,
+                    StringKind::Raw(hashes) => hashes,
+                };
+                for _ in 0..delimiter_len {
+                    chars.next();
+                    col += 1;
+                }
+                // Raw strings keep an `r` so the parser can leave them uninterpolated
+                let prefix = match string_kind {
+                    StringKind::Raw(_) => "r",
+                    _ => "",
+                };
+                tokens.push(Token {
+                    value: Value::Ident(format!(
+                        "{}{}{}{}",
+                        prefix, start_quote, value, start_quote
+                    )),
+                    position: start,
                 });
-                is_escape = false;
+                start_quote = char::default();
+                in_quote = false;
+                current_token.clear();
                 continue;
             }
 
-            if (in_quote && c != '"' && c != '\'') || is_comment {
+            if is_comment {
                 current_token.push(c);
                 continue;
             }
 
             match c {
                 '"' | '\'' => {
-                    if in_quote {
-                        tokens.push(Token {
-                            value: Value::Ident(format!(
-                                "{}{}{}",
-                                start_quote,
-                                current_token.clone(),
-                                c
-                            )),
-                            position: position.clone(),
-                        });
-                        start_quote = char::default();
+                    string_kind = if c == '"' && rest.starts_with("\"\"") {
+                        chars.next();
+                        chars.next();
+                        col += 2;
+                        StringKind::Multiline
+                    } else if c == '"' && is_raw_prefix(&current_token) {
+                        StringKind::Raw(current_token.len() - 1)
                     } else {
-                        start_quote = c;
+                        StringKind::Quoted
+                    };
+                    if !matches!(string_kind, StringKind::Raw(_)) {
+                        token_start = Some(position.clone());
                     }
-                    in_quote = !in_quote;
+                    start_quote = c;
+                    in_quote = true;
                     current_token.clear();
                 }
-                '\\' => {
-                    is_escape = true;
-                }
                 '[' => {
                     tokens.push(Token {
                         value: Value::LeftSquareBracket,
@@ -150,7 +220,11 @@ impl Lexer {
                     if current_token == "/" {
                         is_comment = true;
                         current_token.clear();
+                        token_start = None;
                     } else {
+                        if current_token.is_empty() {
+                            token_start = Some(position.clone());
+                        }
                         current_token.push(c);
                         continue 'lexer_loop;
                     }
@@ -158,14 +232,20 @@ impl Lexer {
                 other => {
                     if other.is_whitespace() && !in_quote && !is_comment {
                         if !current_token.is_empty() {
-                            let identifier = self.lex_current_token(current_token.clone());
+                            let identifier = self.lex_current_token(
+                                current_token.clone(),
+                                rest.trim_start().chars().next(),
+                            );
                             tokens.push(Token {
                                 value: identifier,
-                                position: position.clone(),
+                                position: token_start.take().unwrap_or(position.clone()),
                             });
                             current_token.clear();
                         }
                     } else {
+                        if current_token.is_empty() {
+                            token_start = Some(position.clone());
+                        }
                         current_token.push(c);
                     }
                     is_others = true;
@@ -173,18 +253,40 @@ impl Lexer {
             }
 
             if !is_comment && !in_quote && !is_others && !current_token.is_empty() {
-                let identifier = self.lex_current_token(current_token.clone());
+                let identifier = self.lex_current_token(current_token.clone(), Some(c));
                 tokens.insert(
                     tokens.len() - 1,
                     Token {
                         value: identifier,
-                        position,
+                        position: token_start.take().unwrap_or(position),
                     },
                 );
                 current_token.clear();
             }
         }
 
-        tokens
+        if in_quote {
+            errors.push(EnvlError {
+                message: ErrorContext::IsntClosed("string".to_string()),
+                position: token_start.take().unwrap_or(Position {
+                    file_path: self.file_path.clone(),
+                    row,
+                    col,
+                }),
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(tokens)
+        } else {
+            Err(errors)
+        }
     }
 }
+
+/// Whether the token before a quote opens a raw string such as `r#"..."#`
+fn is_raw_prefix(token: &str) -> bool {
+    token
+        .strip_prefix('r')
+        .is_some_and(|hashes| hashes.chars().all(|c| c == '#'))
+}
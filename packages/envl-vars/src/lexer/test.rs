@@ -1,10 +1,13 @@
 #[cfg(test)]
 mod lexer_test {
+    use envl_utils::error::ErrorContext;
+
     use crate::{lexer::Lexer, misc::token::Value};
 
     fn generate_tokens(code: String) -> Vec<Value> {
         let lex = Lexer::new("test.envl".to_string(), code);
         lex.generate()
+            .unwrap()
             .into_iter()
             .map(|t| t.value)
             .collect::<Vec<_>>()
@@ -34,6 +37,50 @@ mod lexer_test {
         assert_eq!(tokens, expect_arr);
     }
 
+    #[test]
+    fn escaped_string_test() {
+        let tokens = generate_tokens("variable = \"it\\'s \\\"quoted\\\"\\n\";".to_string());
+        let expect_arr = vec![
+            Value::Ident("variable".to_string()),
+            Value::Equal,
+            Value::Ident("\"it's \"quoted\"\n\"".to_string()),
+            Value::Semi,
+        ];
+        assert_eq!(tokens, expect_arr);
+    }
+
+    #[test]
+    fn mixed_quote_test() {
+        let tokens = generate_tokens("a = \"it's\"; b = '\"';".to_string());
+        let expect_arr = vec![
+            Value::Ident("a".to_string()),
+            Value::Equal,
+            Value::Ident("\"it's\"".to_string()),
+            Value::Semi,
+            Value::Ident("b".to_string()),
+            Value::Equal,
+            Value::Ident("'\"'".to_string()),
+            Value::Semi,
+        ];
+        assert_eq!(tokens, expect_arr);
+    }
+
+    #[test]
+    fn escaped_backslash_test() {
+        let tokens = generate_tokens("a = \"dir\\\\\"; b = 'x\\'';".to_string());
+        let expect_arr = vec![
+            Value::Ident("a".to_string()),
+            Value::Equal,
+            Value::Ident("\"dir\\\"".to_string()),
+            Value::Semi,
+            Value::Ident("b".to_string()),
+            Value::Equal,
+            Value::Ident("'x''".to_string()),
+            Value::Semi,
+        ];
+        assert_eq!(tokens, expect_arr);
+    }
+
     #[test]
     fn char_test() {
         let tokens = generate_tokens("variable = 'a';".to_string());
@@ -159,4 +206,97 @@ mod lexer_test {
         ];
         assert_eq!(tokens, expect_arr);
     }
+
+    #[test]
+    fn multiline_string_test() {
+        let tokens = generate_tokens(
+            "cert = \"\"\"\n    -----BEGIN-----\n      \\u{41}BC\n    -----END-----\n    \"\"\";"
+                .to_string(),
+        );
+        let expect_arr = vec![
+            Value::Ident("cert".to_string()),
+            Value::Equal,
+            Value::Ident("\"-----BEGIN-----\n  ABC\n-----END-----\"".to_string()),
+            Value::Semi,
+        ];
+        assert_eq!(tokens, expect_arr);
+    }
+
+    #[test]
+    fn raw_string_test() {
+        let tokens =
+            generate_tokens("a = r\"C:\\path\\n\"; b = r#\"{\"key\": \"value\"}\"#;".to_string());
+        let expect_arr = vec![
+            Value::Ident("a".to_string()),
+            Value::Equal,
+            Value::Ident("r\"C:\\path\\n\"".to_string()),
+            Value::Semi,
+            Value::Ident("b".to_string()),
+            Value::Equal,
+            Value::Ident("r\"{\"key\": \"value\"}\"".to_string()),
+            Value::Semi,
+        ];
+        assert_eq!(tokens, expect_arr);
+    }
+
+    #[test]
+    fn unicode_escape_test() {
+        let tokens = generate_tokens("variable = \"\\u{48}i \\u{1F600}\";".to_string());
+        let expect_arr = vec![
+            Value::Ident("variable".to_string()),
+            Value::Equal,
+            Value::Ident("\"Hi 😀\"".to_string()),
+            Value::Semi,
+        ];
+        assert_eq!(tokens, expect_arr);
+    }
+
+    #[test]
+    fn invalid_escape_test() {
+        let lex = Lexer::new(
+            "test.envl".to_string(),
+            "a = \"x\\u{zz}\";\nb = \"\"\"\n  y \\u{110000}\n  \"\"\";\nc = r\"\\u{zz}\";"
+                .to_string(),
+        );
+        let errors = lex
+            .generate()
+            .unwrap_err()
+            .into_iter()
+            .map(|err| (err.message, err.position.row, err.position.col))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                (ErrorContext::InvalidEscape("\\u{zz}".to_string()), 1, 7),
+                (ErrorContext::InvalidEscape("\\u{110000}".to_string()), 3, 5),
+            ]
+        );
+    }
+
+    #[test]
+    fn string_position_test() {
+        let lex = Lexer::new(
+            "test.envl".to_string(),
+            "a = \"\"\"\n  x\n  \"\"\";\nb = r#\"y\"#;".to_string(),
+        );
+        let positions = lex
+            .generate()
+            .unwrap()
+            .into_iter()
+            .map(|t| (t.position.row, t.position.col))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            positions,
+            vec![
+                (1, 1),
+                (1, 3),
+                (1, 5),
+                (3, 6),
+                (4, 1),
+                (4, 3),
+                (4, 5),
+                (4, 11)
+            ]
+        );
+    }
 }
@@ -1,14 +1,35 @@
+use std::path::PathBuf;
+
 use envl_utils::error::EnvlError;
 
 use crate::{lexer::Lexer, misc::variable::Variable, parser::Parser};
 
+#[cfg(feature = "serde")]
+pub mod de;
 pub mod lexer;
 pub mod misc;
 pub mod parser;
+#[cfg(feature = "serde")]
+pub mod ser;
+
+#[cfg(feature = "serde")]
+pub use crate::{de::from_str, ser::to_string};
 
-pub fn generate_ast(file_path: String, code: String) -> Result<Vec<Variable>, EnvlError> {
+pub fn generate_ast(file_path: String, code: String) -> Result<Vec<Variable>, Vec<EnvlError>> {
     let lexer = Lexer::new(file_path, code);
-    let tokens = lexer.generate();
+    let tokens = lexer.generate()?;
     let parser = Parser::new(tokens);
     parser.parse()
 }
+
+pub fn generate_ast_with_root(
+    file_path: String,
+    code: String,
+    root: PathBuf,
+) -> Result<Vec<Variable>, Vec<EnvlError>> {
+    let lexer = Lexer::new(file_path, code);
+    let tokens = lexer.generate()?;
+    let mut parser = Parser::new(tokens);
+    parser.root = Some(root);
+    parser.parse()
+}
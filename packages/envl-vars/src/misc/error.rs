@@ -0,0 +1,55 @@
+use std::fmt;
+
+use envl_utils::{error::EnvlError, types::Position};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    pub message: String,
+    pub position: Option<Position>,
+}
+
+impl Error {
+    pub fn new(message: String) -> Self {
+        Self {
+            message,
+            position: None,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error: {}", self.message)?;
+        if let Some(position) = &self.position {
+            write!(
+                f,
+                " (at {}:{}:{})",
+                position.file_path, position.row, position.col
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl serde::ser::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
+
+impl serde::de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self::new(msg.to_string())
+    }
+}
+
+impl From<EnvlError> for Error {
+    fn from(err: EnvlError) -> Self {
+        Self {
+            message: err.message.to_string(),
+            position: Some(err.position),
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "serde")]
+pub mod error;
 pub mod num;
 pub mod token;
 pub mod variable;
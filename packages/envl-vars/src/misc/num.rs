@@ -1,14 +1,207 @@
-pub fn is_num(input: String, sign: bool) -> bool {
-    let mut signed = false;
-    for c in input.chars() {
-        if sign && !signed && c == '-' {
-            signed = true;
-            continue;
+use std::fmt;
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Number {
+    /// The literal as written in the file
+    pub raw: String,
+    pub kind: NumberKind,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum NumberKind {
+    /// Digits without sign, prefix and separators
+    Integer {
+        negative: bool,
+        radix: u32,
+        digits: String,
+    },
+    /// Normalized decimal notation that `f64::from_str` accepts
+    Float(String),
+}
+
+impl Number {
+    pub fn parse(input: &str) -> Option<Number> {
+        let (negative, body) = match input.strip_prefix('-') {
+            Some(body) => (true, body),
+            None => (false, input.strip_prefix('+').unwrap_or(input)),
+        };
+        let radix = match body.get(..2) {
+            Some("0x") => 16,
+            Some("0o") => 8,
+            Some("0b") => 2,
+            _ => 10,
+        };
+
+        if radix != 10 {
+            let digits = strip_separators(&body[2..], radix)?;
+            return Some(Number {
+                raw: input.to_string(),
+                kind: NumberKind::Integer {
+                    negative,
+                    radix,
+                    digits,
+                },
+            });
+        }
+
+        let (mantissa, exponent) = match body.find(['e', 'E']) {
+            Some(i) => (&body[..i], Some(&body[(i + 1)..])),
+            None => (body, None),
+        };
+        let (integer, fraction) = match mantissa.split_once('.') {
+            Some((integer, fraction)) => (integer, Some(fraction)),
+            None => (mantissa, None),
+        };
+        let digits = strip_separators(integer, 10)?;
+
+        if fraction.is_none() && exponent.is_none() {
+            return Some(Number {
+                raw: input.to_string(),
+                kind: NumberKind::Integer {
+                    negative,
+                    radix,
+                    digits,
+                },
+            });
+        }
+
+        let mut value = String::new();
+        if negative {
+            value.push('-');
+        }
+        value.push_str(&digits);
+        if let Some(fraction) = fraction {
+            value.push('.');
+            value.push_str(&strip_separators(fraction, 10)?);
+        }
+        if let Some(exponent) = exponent {
+            let (exponent_negative, exponent) = match exponent.strip_prefix('-') {
+                Some(exponent) => (true, exponent),
+                None => (false, exponent.strip_prefix('+').unwrap_or(exponent)),
+            };
+            value.push('e');
+            if exponent_negative {
+                value.push('-');
+            }
+            value.push_str(&strip_separators(exponent, 10)?);
+        }
+
+        Some(Number {
+            raw: input.to_string(),
+            kind: NumberKind::Float(value),
+        })
+    }
+
+    pub fn to_i64(&self) -> Result<i64, String> {
+        match &self.kind {
+            NumberKind::Integer {
+                negative,
+                radix,
+                digits,
+            } => {
+                let signed = if *negative {
+                    format!("-{}", digits)
+                } else {
+                    digits.to_owned()
+                };
+                i64::from_str_radix(&signed, *radix).map_err(|_| self.out_of_range("int"))
+            }
+            NumberKind::Float(_) => Err(format!("{} isn't an integer", self.raw)),
+        }
+    }
+
+    pub fn to_u64(&self) -> Result<u64, String> {
+        match &self.kind {
+            NumberKind::Integer {
+                negative,
+                radix,
+                digits,
+            } => {
+                if *negative && digits.chars().any(|c| c != '0') {
+                    return Err(self.out_of_range("uint"));
+                }
+                u64::from_str_radix(digits, *radix).map_err(|_| self.out_of_range("uint"))
+            }
+            NumberKind::Float(_) => Err(format!("{} isn't an integer", self.raw)),
+        }
+    }
+
+    pub fn to_f64(&self) -> Result<f64, String> {
+        let value = match &self.kind {
+            NumberKind::Integer {
+                negative,
+                radix,
+                digits,
+            } => {
+                let value = digits.chars().fold(0.0, |acc, c| {
+                    acc * f64::from(*radix) + f64::from(c.to_digit(*radix).unwrap_or(0))
+                });
+                if *negative {
+                    -value
+                } else {
+                    value
+                }
+            }
+            NumberKind::Float(value) => value.parse::<f64>().unwrap_or(f64::INFINITY),
+        };
+
+        if value.is_finite() {
+            Ok(value)
+        } else {
+            Err(self.out_of_range("float"))
+        }
+    }
+
+    fn out_of_range(&self, type_name: &str) -> String {
+        match type_name {
+            "int" => format!(
+                "{} is out of range for int ({} to {})",
+                self.raw,
+                i64::MIN,
+                i64::MAX
+            ),
+            "uint" => format!("{} is out of range for uint (0 to {})", self.raw, u64::MAX),
+            _ => format!("{} is out of range for {}", self.raw, type_name),
         }
-        if !c.is_ascii_digit() {
-            return false;
+    }
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            NumberKind::Integer {
+                negative,
+                radix,
+                digits,
+            } => match u128::from_str_radix(digits, *radix) {
+                Ok(value) if *negative && value != 0 => write!(f, "-{}", value),
+                Ok(value) => write!(f, "{}", value),
+                Err(_) => write!(f, "{}", self.raw),
+            },
+            NumberKind::Float(value) => write!(f, "{}", value),
         }
     }
+}
 
-    true
+/// Whether the input starts like a number, so a malformed literal can be
+/// reported as such instead of as an unknown value
+pub fn is_num_like(input: &str) -> bool {
+    let body = input
+        .strip_prefix('-')
+        .or_else(|| input.strip_prefix('+'))
+        .unwrap_or(input);
+    body.starts_with(|c: char| c.is_ascii_digit())
+}
+
+fn strip_separators(input: &str, radix: u32) -> Option<String> {
+    if input.is_empty() || input.starts_with('_') || input.ends_with('_') || input.contains("__") {
+        return None;
+    }
+
+    let digits = input.chars().filter(|c| *c != '_').collect::<String>();
+    if digits.chars().all(|c| c.is_digit(radix)) {
+        Some(digits)
+    } else {
+        None
+    }
 }
@@ -9,6 +9,8 @@ pub enum Value {
     RightCurlyBracket,
     LeftCurlyBracket,
     Struct,
+    Map,
+    Include,
     Comma,
     Colon,
     Equal,
@@ -1,15 +1,21 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use envl_utils::types::Position;
 
+use crate::misc::num::Number;
+
 #[derive(Clone, PartialEq, Debug)]
 pub enum VariableValue {
     String(String),
-    Number(String),
+    /// A raw string literal, which is never interpolated
+    RawString(String),
+    Number(Number),
+    Null,
     Bool(bool),
     Char(char),
     Array(Vec<VariableValue>),
     Struct(HashMap<String, VariableValue>),
+    Map(BTreeMap<String, VariableValue>),
 }
 
 #[derive(Debug)]
@@ -17,6 +23,8 @@ pub struct Variable {
     pub name: String,
     pub value: VariableValue,
     pub position: Position,
+    /// Where each string in `value` was written, keyed by its path inside `value`
+    pub string_positions: HashMap<Vec<String>, Position>,
 }
 
 #[derive(Debug, PartialEq)]
@@ -7,100 +7,112 @@ use crate::{
         token::{Token, Value},
         variable::VariableValue,
     },
-    parser::Parser,
+    parser::{element_path, skip_element, Parser},
 };
 
 impl Parser {
     pub fn parse_array<'a>(
         &self,
         tokens: &mut Iter<'a, Token>,
-    ) -> Result<VariableValue, EnvlError> {
+        path: &[String],
+    ) -> Result<VariableValue, Vec<EnvlError>> {
         let mut array_contents = Vec::new();
-        let mut parser_error: Option<EnvlError> = None;
+        let mut errors = Vec::new();
         let mut comma_used = false;
         let mut array_closed = false;
         let mut last_position = None;
 
         'parse_array_loop: loop {
+            let element_start = tokens.clone();
+
             if let Some(token) = tokens.next() {
+                // Skips to the next element of the array and keeps parsing from there
+                macro_rules! recover {
+                    ($errs: expr) => {
+                        errors.extend($errs);
+
+                        *tokens = element_start.clone();
+                        if skip_element(tokens, &Value::RightSquareBracket) {
+                            array_closed = true;
+                            break 'parse_array_loop;
+                        }
+                        comma_used = true;
+                        continue 'parse_array_loop;
+                    };
+                }
+                macro_rules! error {
+                    ($msg: expr) => {
+                        recover!([EnvlError {
+                            message: $msg,
+                            position: token.position.clone(),
+                        }]);
+                    };
+                }
+                macro_rules! insert {
+                    ($value: expr) => {
+                        if !array_contents.is_empty() && !comma_used {
+                            error!(ErrorContext::Required("Comma".to_string()));
+                        }
+                        array_contents.push($value);
+                        comma_used = false;
+                    };
+                }
+
                 last_position = Some(token.position.clone());
+                let value_path = element_path(path, array_contents.len().to_string());
 
                 match &token.value {
-                    Value::LeftSquareBracket => match self.parse_array(tokens) {
-                        Ok(v) => {
-                            if !array_contents.is_empty() && !comma_used {
-                                parser_error = Some(EnvlError {
-                                    message: ErrorContext::Required("Comma".to_string()),
-                                    position: token.position.clone(),
-                                });
-                                break 'parse_array_loop;
-                            }
-                            array_contents.push(v.clone());
-                            comma_used = false;
+                    Value::LeftSquareBracket => match self.parse_array(tokens, &value_path) {
+                        Ok(value) => {
+                            insert!(value);
                         }
-                        Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_array_loop;
+                        Err(errs) => {
+                            recover!(errs);
                         }
                     },
+                    // The statement ends before the literal is closed
+                    Value::Semi => {
+                        break 'parse_array_loop;
+                    }
                     Value::RightSquareBracket => {
                         array_closed = true;
                         break 'parse_array_loop;
                     }
                     Value::Comma => {
                         if comma_used {
-                            parser_error = Some(EnvlError {
-                                message: ErrorContext::Required("Comma".to_string()),
-                                position: token.position.clone(),
-                            });
-                            break 'parse_array_loop;
+                            error!(ErrorContext::InvalidPosition("Comma".to_string()));
                         }
                         comma_used = true;
                     }
-                    Value::Struct => match self.parse_struct(tokens) {
+                    Value::Struct => match self.parse_struct(tokens, &value_path) {
                         Ok(value) => {
-                            if !array_contents.is_empty() && !comma_used {
-                                parser_error = Some(EnvlError {
-                                    message: ErrorContext::Required("Comma".to_string()),
-                                    position: token.position.clone(),
-                                });
-                                break 'parse_array_loop;
-                            }
-                            array_contents.push(value.clone());
-                            comma_used = false;
+                            insert!(value);
                         }
-                        Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_array_loop;
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::Map => match self.parse_map(tokens, &value_path) {
+                        Ok(value) => {
+                            insert!(value);
+                        }
+                        Err(errs) => {
+                            recover!(errs);
                         }
                     },
                     Value::Ident(value) => {
-                        let value = self.parse_value(value, &token.position);
-                        match value {
-                            Ok(v) => {
-                                if !array_contents.is_empty() && !comma_used {
-                                    parser_error = Some(EnvlError {
-                                        message: ErrorContext::Required("Comma".to_string()),
-                                        position: token.position.clone(),
-                                    });
-                                    break 'parse_array_loop;
-                                }
-                                array_contents.push(v.clone());
-                                comma_used = false;
+                        match self.parse_element_value(value, &token.position, value_path) {
+                            Ok(value) => {
+                                insert!(value);
                             }
                             Err(err) => {
-                                parser_error = Some(err);
-                                break 'parse_array_loop;
+                                recover!([err]);
                             }
                         }
                     }
                     Value::Comment(_) => {}
                     _ => {
-                        parser_error = Some(EnvlError {
-                            message: ErrorContext::InvalidSyntax,
-                            position: token.position.clone(),
-                        });
-                        break 'parse_array_loop;
+                        error!(ErrorContext::InvalidSyntax);
                     }
                 }
             } else {
@@ -108,18 +120,19 @@ impl Parser {
             }
         }
 
-        if let Some(err) = parser_error {
-            Err(err)
-        } else {
-            if let Some(position) = last_position {
-                if !array_closed {
-                    return Err(EnvlError {
-                        message: ErrorContext::IsntClosed("Array".to_string()),
-                        position,
-                    });
-                }
+        if let Some(position) = last_position {
+            if !array_closed {
+                errors.push(EnvlError {
+                    message: ErrorContext::IsntClosed("Array".to_string()),
+                    position,
+                });
             }
+        }
+
+        if errors.is_empty() {
             Ok(VariableValue::Array(array_contents))
+        } else {
+            Err(errors)
         }
     }
 }
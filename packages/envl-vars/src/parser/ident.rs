@@ -30,7 +30,7 @@ impl Parser {
                 Ok(ParsedIdent::Name(value.clone()))
             }
         } else if var.value.is_none() && *equal_used {
-            let var_value = self.parse_value(&value, position);
+            let var_value = self.parse_element_value(&value, position, Vec::new());
             match var_value {
                 Ok(var_value) => Ok(ParsedIdent::Value(var_value)),
                 Err(err) => Err(err),
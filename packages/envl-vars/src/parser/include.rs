@@ -0,0 +1,102 @@
+use std::{
+    fs::read_to_string,
+    path::{Path, PathBuf},
+    slice::Iter,
+};
+
+use envl_utils::{
+    error::{EnvlError, ErrorContext},
+    types::Position,
+};
+
+use crate::{
+    lexer::Lexer,
+    misc::{
+        token::{Token, Value},
+        variable::{Variable, VariableValue},
+    },
+    parser::Parser,
+};
+
+impl Parser {
+    pub fn parse_include<'a>(
+        &self,
+        tokens: &mut Iter<'a, Token>,
+        position: &Position,
+    ) -> Result<Vec<Variable>, Vec<EnvlError>> {
+        let include_path = match tokens.next() {
+            Some(Token {
+                value: Value::Ident(v),
+                position,
+            }) => match self.parse_value(v, position) {
+                Ok(VariableValue::String(s) | VariableValue::RawString(s)) => s,
+                _ => {
+                    return Err(vec![EnvlError {
+                        message: ErrorContext::InvalidType,
+                        position: position.clone(),
+                    }]);
+                }
+            },
+            _ => {
+                return Err(vec![EnvlError {
+                    message: ErrorContext::Required("File path".to_string()),
+                    position: position.clone(),
+                }]);
+            }
+        };
+        match tokens.next() {
+            Some(Token {
+                value: Value::Semi, ..
+            }) => {}
+            _ => {
+                return Err(vec![EnvlError {
+                    message: ErrorContext::Required("Semicolon".to_string()),
+                    position: position.clone(),
+                }]);
+            }
+        }
+
+        let error = |message: ErrorContext| {
+            vec![EnvlError {
+                message,
+                position: position.clone(),
+            }]
+        };
+
+        let current_file = Path::new(&position.file_path);
+        let current_dir = match current_file.parent() {
+            Some(parent) => parent.to_path_buf(),
+            None => PathBuf::new(),
+        };
+        let file_path = current_dir.join(&include_path);
+        let root = match &self.root {
+            Some(root) => root.to_owned(),
+            None => current_dir,
+        };
+
+        let canonical_path = file_path
+            .canonicalize()
+            .map_err(|_| error(ErrorContext::IncludeNotFound(include_path.to_owned())))?;
+        let canonical_root = root.canonicalize().unwrap_or(root);
+        if !canonical_path.starts_with(&canonical_root) {
+            return Err(error(ErrorContext::OutsideRoot(include_path)));
+        }
+
+        let mut included_files = self.included_files.to_owned();
+        if let Ok(current_file) = current_file.canonicalize() {
+            included_files.push(current_file);
+        }
+        if included_files.contains(&canonical_path) {
+            return Err(error(ErrorContext::IncludeCycle(include_path)));
+        }
+
+        let code = read_to_string(&canonical_path)
+            .map_err(|_| error(ErrorContext::IncludeNotFound(include_path.to_owned())))?;
+        let lexer = Lexer::new(file_path.display().to_string(), code);
+        let mut parser = Parser::new(lexer.generate()?);
+        parser.root = Some(canonical_root);
+        parser.included_files = included_files;
+
+        parser.parse()
+    }
+}
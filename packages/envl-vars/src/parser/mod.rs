@@ -1,6 +1,9 @@
-use std::collections::HashSet;
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, slice::Iter};
 
-use envl_utils::error::{EnvlError, ErrorContext};
+use envl_utils::{
+    error::{EnvlError, ErrorContext},
+    types::Position,
+};
 
 use crate::misc::{
     token::{Token, Value},
@@ -9,6 +12,8 @@ use crate::misc::{
 
 pub mod array;
 pub mod ident;
+pub mod include;
+pub mod parse_map;
 pub mod parse_struct;
 pub mod test;
 pub mod value;
@@ -26,14 +31,23 @@ pub struct Var {
 
 pub struct Parser {
     pub tokens: Vec<Token>,
+    pub root: Option<PathBuf>,
+    pub included_files: Vec<PathBuf>,
+    /// Positions of the strings in the variable being parsed
+    pub string_positions: RefCell<HashMap<Vec<String>, Position>>,
 }
 
 impl Parser {
     pub fn new(tokens: Vec<Token>) -> Self {
-        Self { tokens }
+        Self {
+            tokens,
+            root: None,
+            included_files: Vec::new(),
+            string_positions: RefCell::new(HashMap::new()),
+        }
     }
 
-    pub fn parse(&self) -> Result<Vec<Variable>, EnvlError> {
+    pub fn parse(&self) -> Result<Vec<Variable>, Vec<EnvlError>> {
         let mut based_token = vec![];
 
         for token in self.tokens.iter() {
@@ -48,14 +62,16 @@ impl Parser {
         }
 
         let mut tokens = based_token.iter();
+        let mut statement_start = tokens.clone();
 
         let mut vars = Vec::new();
         let mut equal_used = false;
+        let mut var_position = None;
         let mut var = Var {
             name: None,
             value: None,
         };
-        let mut parser_error: Option<EnvlError> = None;
+        let mut errors = Vec::new();
 
         macro_rules! clear {
             () => {{
@@ -64,24 +80,48 @@ impl Parser {
                     value: None,
                 };
                 equal_used = false;
+                var_position = None;
+                self.string_positions.borrow_mut().clear();
             }};
         }
 
-        macro_rules! error {
-            ($pos: ident) => {
-                parser_error = Some(EnvlError {
-                    message: ErrorContext::InvalidSyntax,
-                    position: $pos,
-                })
-            };
-        }
-
         'parse_loop: loop {
+            // Skips to the end of the broken statement and keeps parsing from there
+            macro_rules! recover {
+                ($err: expr) => {
+                    recover!(@all [$err])
+                };
+                (@all $errs: expr) => {{
+                    errors.extend($errs);
+                    tokens = statement_start.clone();
+                    for token in tokens.by_ref() {
+                        if token.value == Value::Semi {
+                            break;
+                        }
+                    }
+                    clear!();
+                    continue 'parse_loop;
+                }};
+            }
+
+            macro_rules! error {
+                ($pos: ident) => {
+                    recover!(EnvlError {
+                        message: ErrorContext::InvalidSyntax,
+                        position: $pos,
+                    })
+                };
+            }
+
+            if var.name.is_none() && !equal_used {
+                statement_start = tokens.clone();
+            }
+
             if let Some(token) = tokens.next() {
                 let value = &token.value;
                 let position = token.position.clone();
                 match value {
-                    Value::LeftSquareBracket => match self.parse_array(&mut tokens) {
+                    Value::LeftSquareBracket => match self.parse_array(&mut tokens, &[]) {
                         Ok(v) => {
                             if var.name.is_some() && var.value.is_none() && equal_used {
                                 var = Var {
@@ -89,26 +129,23 @@ impl Parser {
                                     value: Some(v.clone()),
                                 }
                             } else {
-                                parser_error = Some(EnvlError {
+                                recover!(EnvlError {
                                     message: ErrorContext::AfterEqual("array".to_string()),
                                     position: position.clone(),
                                 });
-                                break 'parse_loop;
                             }
                         }
-                        Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_loop;
+                        Err(errs) => {
+                            recover!(@all errs);
                         }
                     },
                     Value::RightSquareBracket => {
-                        parser_error = Some(EnvlError {
+                        recover!(EnvlError {
                             message: ErrorContext::InvalidSyntax,
                             position: position.clone(),
                         });
-                        break 'parse_loop;
                     }
-                    Value::Struct => match self.parse_struct(&mut tokens) {
+                    Value::Struct => match self.parse_struct(&mut tokens, &[]) {
                         Ok(v) => {
                             if var.name.is_some() && var.value.is_none() && equal_used {
                                 var = Var {
@@ -116,43 +153,68 @@ impl Parser {
                                     value: Some(v.clone()),
                                 }
                             } else {
-                                parser_error = Some(EnvlError {
+                                recover!(EnvlError {
                                     message: ErrorContext::AfterEqual("struct".to_string()),
                                     position: position.clone(),
                                 });
-                                break 'parse_loop;
                             }
                         }
-                        Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_loop;
+                        Err(errs) => {
+                            recover!(@all errs);
+                        }
+                    },
+                    Value::Map => match self.parse_map(&mut tokens, &[]) {
+                        Ok(v) => {
+                            if var.name.is_some() && var.value.is_none() && equal_used {
+                                var = Var {
+                                    name: var.name,
+                                    value: Some(v.clone()),
+                                }
+                            } else {
+                                recover!(EnvlError {
+                                    message: ErrorContext::AfterEqual("map".to_string()),
+                                    position: position.clone(),
+                                });
+                            }
+                        }
+                        Err(errs) => {
+                            recover!(@all errs);
                         }
                     },
                     Value::RightCurlyBracket => {
-                        parser_error = Some(EnvlError {
+                        recover!(EnvlError {
                             message: ErrorContext::InvalidSyntax,
                             position: position.clone(),
                         });
-                        break 'parse_loop;
                     }
                     Value::Colon => {
-                        parser_error = Some(EnvlError {
+                        recover!(EnvlError {
                             message: ErrorContext::InvalidPosition("Colon".to_string()),
                             position: position.clone(),
                         });
-                        break 'parse_loop;
                     }
                     Value::Comma => {
-                        parser_error = Some(EnvlError {
+                        recover!(EnvlError {
                             message: ErrorContext::InvalidPosition("Comma".to_string()),
                             position: position.clone(),
                         });
-                        break 'parse_loop;
+                    }
+                    Value::Include => {
+                        if var.name.is_some() || equal_used {
+                            error!(position);
+                        }
+                        match self.parse_include(&mut tokens, &position) {
+                            Ok(included_vars) => {
+                                vars.extend(included_vars);
+                            }
+                            Err(errs) => {
+                                recover!(@all errs);
+                            }
+                        }
                     }
                     Value::Equal => {
                         if equal_used {
                             error!(position);
-                            break 'parse_loop;
                         }
                         match (&var.name, &var.value) {
                             (Some(_), None) => {
@@ -160,27 +222,25 @@ impl Parser {
                             }
                             _ => {
                                 error!(position);
-                                break 'parse_loop;
                             }
                         }
                     }
                     Value::Semi => {
                         if !equal_used {
                             error!(position);
-                            break 'parse_loop;
                         }
                         match (&var.name, &var.value) {
                             (Some(name), Some(value)) => {
                                 vars.push(Variable {
                                     name: name.clone(),
                                     value: value.clone(),
-                                    position: position.clone(),
+                                    position: var_position.unwrap_or(position),
+                                    string_positions: self.string_positions.take(),
                                 });
                                 clear!();
                             }
                             _ => {
                                 error!(position);
-                                break 'parse_loop;
                             }
                         }
                     }
@@ -188,6 +248,7 @@ impl Parser {
                         match self.parse_ident(value.clone(), &var, &position, &equal_used) {
                             Ok(ident) => match ident {
                                 ParsedIdent::Name(name) => {
+                                    var_position = Some(position.clone());
                                     var = Var {
                                         name: Some(name.clone()),
                                         value: None,
@@ -201,17 +262,15 @@ impl Parser {
                                 }
                             },
                             Err(e) => {
-                                parser_error = Some(e);
-                                break 'parse_loop;
+                                recover!(e);
                             }
                         }
                     }
                     _ => {
-                        parser_error = Some(EnvlError {
+                        recover!(EnvlError {
                             message: ErrorContext::InvalidSyntax,
                             position: token.position.clone(),
                         });
-                        break 'parse_loop;
                     }
                 }
             } else {
@@ -219,29 +278,64 @@ impl Parser {
             }
         }
 
-        if let Some(err) = parser_error {
-            return Err(err);
-        }
+        errors.extend(self.duplicate_check(&vars));
 
-        if let Some(err) = self.duplicate_check(&vars) {
-            return Err(err);
+        if errors.is_empty() {
+            Ok(vars)
+        } else {
+            Err(errors)
         }
-
-        Ok(vars)
     }
 
-    fn duplicate_check(&self, vars: &Vec<Variable>) -> Option<EnvlError> {
-        let mut hs = HashSet::new();
+    fn duplicate_check(&self, vars: &Vec<Variable>) -> Vec<EnvlError> {
+        let mut hm = HashMap::new();
+        let mut errors = Vec::new();
 
         for var in vars {
-            if !hs.insert(&var.name) {
-                return Some(EnvlError {
-                    message: ErrorContext::Duplicate(var.name.clone()),
+            if let Some(position) = hm.insert(&var.name, &var.position) {
+                let message = if position.file_path == var.position.file_path {
+                    ErrorContext::Duplicate(var.name.clone())
+                } else {
+                    ErrorContext::DuplicateAt(var.name.clone(), position.clone())
+                };
+                errors.push(EnvlError {
+                    message,
                     position: var.position.clone(),
                 });
             }
         }
 
-        None
+        errors
     }
 }
+
+/// Moves past a broken element of a struct, array or map literal, ending after
+/// the next `,` at the literal's own depth. Returns `true` when the literal's
+/// `close` comes first. Stops in front of a `;`, which ends the statement
+fn skip_element(tokens: &mut Iter<'_, Token>, close: &Value) -> bool {
+    let mut depth: usize = 0;
+
+    while let Some(token) = tokens.clone().next() {
+        if token.value == Value::Semi {
+            break;
+        }
+        tokens.next();
+
+        match &token.value {
+            value if depth == 0 && value == close => return true,
+            Value::Comma if depth == 0 => return false,
+            Value::LeftCurlyBracket | Value::LeftSquareBracket => depth += 1,
+            Value::RightCurlyBracket | Value::RightSquareBracket => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    false
+}
+
+/// The path of an element inside the value at `path`
+fn element_path(path: &[String], segment: String) -> Vec<String> {
+    let mut path = path.to_vec();
+    path.push(segment);
+    path
+}
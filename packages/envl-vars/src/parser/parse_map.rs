@@ -0,0 +1,195 @@
+use std::{collections::BTreeMap, slice::Iter};
+
+use envl_utils::error::{EnvlError, ErrorContext};
+
+use crate::{
+    misc::{
+        token::{Token, Value},
+        variable::VariableValue,
+    },
+    parser::{element_path, skip_element, Parser},
+};
+
+impl Parser {
+    pub fn parse_map<'a>(
+        &self,
+        tokens: &mut Iter<'a, Token>,
+        path: &[String],
+    ) -> Result<VariableValue, Vec<EnvlError>> {
+        let mut in_block = false;
+        let mut map = BTreeMap::new();
+        let mut errors = Vec::new();
+        let mut comma_used = false;
+        let mut colon_used = false;
+        let mut map_closed = false;
+        let mut last_position = None;
+        let mut element_key: Option<String> = None;
+        let mut element_start = tokens.clone();
+
+        'parse_map_loop: loop {
+            if element_key.is_none() && !colon_used {
+                element_start = tokens.clone();
+            }
+
+            if let Some(token) = tokens.next() {
+                // Skips to the next entry of the map and keeps parsing from there
+                macro_rules! recover {
+                    ($errs: expr) => {
+                        errors.extend($errs);
+                        if !in_block {
+                            break 'parse_map_loop;
+                        }
+
+                        *tokens = element_start.clone();
+                        element_key = None;
+                        colon_used = false;
+
+                        if skip_element(tokens, &Value::RightCurlyBracket) {
+                            map_closed = true;
+                            break 'parse_map_loop;
+                        }
+                        comma_used = true;
+                        continue 'parse_map_loop;
+                    };
+                }
+                macro_rules! error {
+                    ($msg: expr) => {
+                        recover!([EnvlError {
+                            message: $msg,
+                            position: token.position.clone(),
+                        }]);
+                    };
+                }
+                macro_rules! insert {
+                    ($value: expr) => {
+                        match element_key {
+                            Some(key) => {
+                                if !colon_used {
+                                    error!(ErrorContext::Required("Colon".to_string()));
+                                }
+                                if !map.is_empty() && !comma_used {
+                                    error!(ErrorContext::Required("Comma".to_string()));
+                                }
+                                if map.contains_key(&key) {
+                                    error!(ErrorContext::Duplicate(key));
+                                }
+                                map.insert(key, $value);
+                                comma_used = false;
+                                colon_used = false;
+                                element_key = None;
+                            }
+                            None => {
+                                error!(ErrorContext::ItemNotSet);
+                            }
+                        }
+                    };
+                }
+
+                last_position = Some(token.position.clone());
+                let value_path = element_path(path, element_key.clone().unwrap_or_default());
+
+                match &token.value {
+                    Value::LeftCurlyBracket => {
+                        if in_block {
+                            error!(ErrorContext::InvalidPosition("{".to_string()));
+                        }
+                        in_block = true;
+                        continue 'parse_map_loop;
+                    }
+                    // The statement ends before the literal is closed
+                    Value::Semi => {
+                        break 'parse_map_loop;
+                    }
+                    Value::RightCurlyBracket => {
+                        map_closed = true;
+                        break 'parse_map_loop;
+                    }
+                    _ => {}
+                }
+
+                if !in_block {
+                    error!(ErrorContext::InvalidSyntax);
+                }
+
+                match &token.value {
+                    Value::Struct => match self.parse_struct(tokens, &value_path) {
+                        Ok(value) => {
+                            insert!(value);
+                        }
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::Map => match self.parse_map(tokens, &value_path) {
+                        Ok(value) => {
+                            insert!(value);
+                        }
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::LeftSquareBracket => match self.parse_array(tokens, &value_path) {
+                        Ok(value) => {
+                            insert!(value);
+                        }
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::Comma => {
+                        if comma_used || element_key.is_some() {
+                            error!(ErrorContext::InvalidPosition("Comma".to_string()));
+                        }
+                        comma_used = true;
+                    }
+                    Value::Colon => {
+                        if colon_used || element_key.is_none() {
+                            error!(ErrorContext::InvalidPosition("Colon".to_string()));
+                        }
+                        colon_used = true;
+                    }
+                    Value::Ident(v) if element_key.is_none() => {
+                        match self.parse_value(v, &token.position) {
+                            Ok(VariableValue::String(key) | VariableValue::RawString(key)) => {
+                                element_key = Some(key);
+                            }
+                            _ => {
+                                error!(ErrorContext::InvalidType);
+                            }
+                        }
+                    }
+                    Value::Ident(v) => {
+                        match self.parse_element_value(v, &token.position, value_path) {
+                            Ok(value) => {
+                                insert!(value);
+                            }
+                            Err(err) => {
+                                recover!([err]);
+                            }
+                        }
+                    }
+                    _ => {
+                        error!(ErrorContext::AfterEqual("map".to_string()));
+                    }
+                }
+            } else {
+                break 'parse_map_loop;
+            }
+        }
+
+        if let Some(position) = last_position {
+            if !map_closed && (in_block || errors.is_empty()) {
+                errors.push(EnvlError {
+                    message: ErrorContext::IsntClosed("map".to_string()),
+                    position,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(VariableValue::Map(map))
+        } else {
+            Err(errors)
+        }
+    }
+}
@@ -10,53 +10,95 @@ use crate::{
         token::{Token, Value},
         variable::VariableValue,
     },
-    parser::Parser,
+    parser::{element_path, skip_element, Parser},
 };
 
 impl Parser {
     pub fn parse_struct<'a>(
         &self,
         tokens: &mut Iter<'a, Token>,
-    ) -> Result<VariableValue, EnvlError> {
+        path: &[String],
+    ) -> Result<VariableValue, Vec<EnvlError>> {
         let mut in_block = false;
         let mut hm = HashMap::new();
-        let mut parser_error = None;
+        let mut errors = Vec::new();
         let mut comma_used = false;
         let mut colon_used = false;
         let mut struct_closed = false;
         let mut last_position = None;
         let mut element_name: Option<String> = None;
-
-        macro_rules! clean {
-            () => {
-                comma_used = false;
-                colon_used = false;
-                element_name = None;
-            };
-        }
+        let mut element_start = tokens.clone();
 
         'parse_struct_loop: loop {
+            if element_name.is_none() && !colon_used {
+                element_start = tokens.clone();
+            }
+
             if let Some(token) = tokens.next() {
-                macro_rules! insert {
-                    ($name: expr, $value: expr) => {
-                        if hm.get(&$name).is_some() {
-                            parser_error = Some(EnvlError {
-                                message: ErrorContext::Duplicate($name.to_string()),
-                                position: token.position.clone(),
-                            });
+                // Skips to the next element of the struct and keeps parsing from there
+                macro_rules! recover {
+                    ($errs: expr) => {
+                        errors.extend($errs);
+                        if !in_block {
+                            break 'parse_struct_loop;
+                        }
+
+                        *tokens = element_start.clone();
+                        element_name = None;
+                        colon_used = false;
+
+                        if skip_element(tokens, &Value::RightCurlyBracket) {
+                            struct_closed = true;
                             break 'parse_struct_loop;
                         }
-                        hm.insert($name, $value);
+                        comma_used = true;
+                        continue 'parse_struct_loop;
+                    };
+                }
+                macro_rules! error {
+                    ($msg: expr) => {
+                        recover!([EnvlError {
+                            message: $msg,
+                            position: token.position.clone(),
+                        }]);
+                    };
+                }
+                macro_rules! insert {
+                    ($value: expr) => {
+                        match element_name.take() {
+                            Some(name) => {
+                                if !colon_used {
+                                    error!(ErrorContext::Required("Colon".to_string()));
+                                }
+                                if !hm.is_empty() && !comma_used {
+                                    error!(ErrorContext::Required("Comma".to_string()));
+                                }
+                                if hm.contains_key(&name) {
+                                    error!(ErrorContext::Duplicate(name));
+                                }
+                                hm.insert(name, $value);
+                                comma_used = false;
+                                colon_used = false;
+                            }
+                            None => {
+                                error!(ErrorContext::ItemNotSet);
+                            }
+                        }
                     };
                 }
 
                 last_position = Some(token.position.clone());
+                let value_path = element_path(path, element_name.clone().unwrap_or_default());
 
                 match &token.value {
-                    Value::LeftCurlyBracket => {
+                    Value::LeftCurlyBracket if !in_block => {
                         in_block = true;
                         continue 'parse_struct_loop;
                     }
+                    // The statement ends before the literal is closed
+                    Value::Semi => {
+                        break 'parse_struct_loop;
+                    }
                     Value::RightCurlyBracket => {
                         struct_closed = true;
                         break 'parse_struct_loop;
@@ -65,148 +107,67 @@ impl Parser {
                 }
 
                 if !in_block {
-                    parser_error = Some(EnvlError {
-                        message: ErrorContext::InvalidSyntax,
-                        position: token.position.clone(),
-                    });
-                    break 'parse_struct_loop;
+                    error!(ErrorContext::InvalidSyntax);
                 }
 
                 match &token.value {
-                    Value::Struct => match self.parse_struct(tokens) {
-                        Ok(value) => match element_name {
-                            Some(name) => {
-                                if !colon_used {
-                                    parser_error = Some(EnvlError {
-                                        message: ErrorContext::InvalidPosition("Colon".to_string()),
-                                        position: token.position.clone(),
-                                    });
-                                    break 'parse_struct_loop;
-                                }
-                                if !hm.is_empty() && !comma_used {
-                                    parser_error = Some(EnvlError {
-                                        message: ErrorContext::InvalidPosition("Comma".to_string()),
-                                        position: token.position.clone(),
-                                    });
-                                    break 'parse_struct_loop;
-                                }
-                                insert!(name, value);
-                                clean!();
-                            }
-                            None => {
-                                parser_error = Some(EnvlError {
-                                    message: ErrorContext::ItemNotSet,
-                                    position: token.position.clone(),
-                                });
-                                break 'parse_struct_loop;
-                            }
-                        },
-                        Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_struct_loop;
+                    Value::Struct => match self.parse_struct(tokens, &value_path) {
+                        Ok(value) => {
+                            insert!(value);
+                        }
+                        Err(errs) => {
+                            recover!(errs);
                         }
                     },
-                    Value::LeftSquareBracket => match self.parse_array(tokens) {
+                    Value::Map => match self.parse_map(tokens, &value_path) {
                         Ok(value) => {
-                            if let Some(name) = element_name {
-                                if !colon_used {
-                                    parser_error = Some(EnvlError {
-                                        message: ErrorContext::Required("Colon".to_string()),
-                                        position: token.position.clone(),
-                                    });
-                                    break 'parse_struct_loop;
-                                }
-                                if !hm.is_empty() && !comma_used {
-                                    parser_error = Some(EnvlError {
-                                        message: ErrorContext::Required("Comma".to_string()),
-                                        position: token.position.clone(),
-                                    });
-                                    break 'parse_struct_loop;
-                                }
-                                insert!(name, value);
-                                clean!();
-                            } else {
-                                parser_error = Some(EnvlError {
-                                    message: ErrorContext::AfterEqual("array".to_string()),
-                                    position: token.position.clone(),
-                                });
-                                break 'parse_struct_loop;
-                            }
+                            insert!(value);
                         }
-                        Err(err) => {
-                            parser_error = Some(err);
-                            break 'parse_struct_loop;
+                        Err(errs) => {
+                            recover!(errs);
+                        }
+                    },
+                    Value::LeftSquareBracket => match self.parse_array(tokens, &value_path) {
+                        Ok(value) => {
+                            insert!(value);
+                        }
+                        Err(errs) => {
+                            recover!(errs);
                         }
                     },
                     Value::Comma => {
-                        if comma_used {
-                            parser_error = Some(EnvlError {
-                                message: ErrorContext::InvalidPosition("Comma".to_string()),
-                                position: token.position.clone(),
-                            });
-                            break 'parse_struct_loop;
+                        if comma_used || element_name.is_some() {
+                            error!(ErrorContext::InvalidPosition("Comma".to_string()));
                         }
                         comma_used = true;
                     }
                     Value::Colon => {
-                        if colon_used {
-                            parser_error = Some(EnvlError {
-                                message: ErrorContext::InvalidPosition("Colon".to_string()),
-                                position: token.position.clone(),
-                            });
-                            break 'parse_struct_loop;
+                        if colon_used || element_name.is_none() {
+                            error!(ErrorContext::InvalidPosition("Colon".to_string()));
                         }
                         colon_used = true;
                     }
-                    Value::Ident(v) => match element_name.clone() {
-                        None => {
-                            if !is_valid_variable_name(v) {
-                                parser_error = Some(EnvlError {
-                                    message: ErrorContext::InvalidName(v.to_string()),
-                                    position: token.position.clone(),
-                                });
-                                break 'parse_struct_loop;
-                            }
-                            element_name = Some(v.clone());
+                    Value::Ident(v) if element_name.is_none() => {
+                        if !is_valid_variable_name(v) {
+                            error!(ErrorContext::InvalidName(v.to_string()));
                         }
-                        Some(name) if colon_used => {
-                            if !hm.is_empty() && !comma_used {
-                                parser_error = Some(EnvlError {
-                                    message: ErrorContext::Required("Comma".to_string()),
-                                    position: token.position.clone(),
-                                });
-                                break 'parse_struct_loop;
+                        element_name = Some(v.clone());
+                    }
+                    Value::Ident(_) if !colon_used => {
+                        error!(ErrorContext::Required("Colon".to_string()));
+                    }
+                    Value::Ident(v) => {
+                        match self.parse_element_value(v, &token.position, value_path) {
+                            Ok(value) => {
+                                insert!(value);
                             }
-                            match self.parse_value(v, &token.position.clone()) {
-                                Ok(value) => {
-                                    insert!(name, value);
-                                    clean!();
-                                }
-                                Err(err) => {
-                                    parser_error = Some(err);
-                                    break 'parse_struct_loop;
-                                }
+                            Err(err) => {
+                                recover!([err]);
                             }
                         }
-                        _ => {
-                            let message = if !colon_used {
-                                ErrorContext::Required("colon".to_string())
-                            } else {
-                                ErrorContext::ItemNotSet
-                            };
-                            parser_error = Some(EnvlError {
-                                message,
-                                position: token.position.clone(),
-                            });
-                            break 'parse_struct_loop;
-                        }
-                    },
+                    }
                     _ => {
-                        parser_error = Some(EnvlError {
-                            message: ErrorContext::AfterEqual("struct".to_string()),
-                            position: token.position.clone(),
-                        });
-                        break 'parse_struct_loop;
+                        error!(ErrorContext::AfterEqual("struct".to_string()));
                     }
                 }
             } else {
@@ -214,18 +175,19 @@ impl Parser {
             }
         }
 
-        if let Some(err) = parser_error {
-            Err(err)
-        } else {
-            if let Some(position) = last_position {
-                if !struct_closed {
-                    return Err(EnvlError {
-                        message: ErrorContext::AfterEqual("struct".to_string()),
-                        position,
-                    });
-                }
+        if let Some(position) = last_position {
+            if !struct_closed && (in_block || errors.is_empty()) {
+                errors.push(EnvlError {
+                    message: ErrorContext::IsntClosed("struct".to_string()),
+                    position,
+                });
             }
+        }
+
+        if errors.is_empty() {
             Ok(VariableValue::Struct(hm))
+        } else {
+            Err(errors)
         }
     }
 }
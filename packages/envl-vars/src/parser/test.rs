@@ -1,22 +1,34 @@
 #[cfg(test)]
 mod parser_test {
-    use std::collections::HashMap;
+    use std::{
+        collections::{BTreeMap, HashMap},
+        env::temp_dir,
+        fs::{create_dir_all, write},
+    };
 
-    use envl_utils::error::ErrorContext;
+    use envl_utils::{error::ErrorContext, types::Position};
 
     use crate::{
+        generate_ast_with_root,
         lexer::Lexer,
-        misc::variable::{Variable, VariableValue, VariableWithoutPosition},
+        misc::{
+            num::{Number, NumberKind},
+            variable::{Variable, VariableValue, VariableWithoutPosition},
+        },
         parser::{EnvlError, Parser},
     };
 
-    fn gen_parsed_vars(code: String) -> Result<Vec<Variable>, EnvlError> {
+    fn gen_parsed_vars(code: String) -> Result<Vec<Variable>, Vec<EnvlError>> {
         let lex = Lexer::new("test.envl".to_string(), code);
-        let tokens = lex.generate();
+        let tokens = lex.generate()?;
         let parser = Parser::new(tokens);
         parser.parse()
     }
 
+    fn num(value: &str) -> VariableValue {
+        VariableValue::Number(Number::parse(value).unwrap())
+    }
+
     fn gen_vars(code: String) -> Vec<VariableWithoutPosition> {
         gen_parsed_vars(code)
             .unwrap()
@@ -35,7 +47,7 @@ mod parser_test {
             result,
             vec![VariableWithoutPosition {
                 name: "variable".to_string(),
-                value: VariableValue::Number("12345".to_string())
+                value: num("12345")
             }]
         );
     }
@@ -47,11 +59,87 @@ mod parser_test {
             result,
             vec![VariableWithoutPosition {
                 name: "variable".to_string(),
-                value: VariableValue::Number("-12345".to_string())
+                value: num("-12345")
             }]
         );
     }
 
+    #[test]
+    fn numeric_grammar_test() {
+        let result = gen_vars(
+            "a = 0.75; b = +1_000; c = -1.5e-3; d = 2E+10; e = 0xFF_FF; f = 0o755; g = -0b1010;"
+                .to_string(),
+        );
+        let kinds = result
+            .iter()
+            .map(|v| match &v.value {
+                VariableValue::Number(n) => n.kind.to_owned(),
+                _ => panic!("{} isn't a number", v.name),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            vec![
+                NumberKind::Float("0.75".to_string()),
+                NumberKind::Integer {
+                    negative: false,
+                    radix: 10,
+                    digits: "1000".to_string()
+                },
+                NumberKind::Float("-1.5e-3".to_string()),
+                NumberKind::Float("2e10".to_string()),
+                NumberKind::Integer {
+                    negative: false,
+                    radix: 16,
+                    digits: "FFFF".to_string()
+                },
+                NumberKind::Integer {
+                    negative: false,
+                    radix: 8,
+                    digits: "755".to_string()
+                },
+                NumberKind::Integer {
+                    negative: true,
+                    radix: 2,
+                    digits: "1010".to_string()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_number_test() {
+        for literal in ["1__0", "1_", "1.", "1e", "0x", "0xFG", "0b102", "1.2.3"] {
+            let result = gen_parsed_vars(format!("variable = {};", literal));
+            assert_eq!(
+                result.unwrap_err()[0].message,
+                ErrorContext::InvalidNumber(literal.to_string())
+            );
+        }
+        for literal in ["_1", ".5"] {
+            let result = gen_parsed_vars(format!("variable = {};", literal));
+            assert_eq!(result.unwrap_err()[0].message, ErrorContext::InvalidType);
+        }
+    }
+
+    #[test]
+    fn null_test() {
+        let result = gen_vars("variable = null; list = [1, null];".to_string());
+        assert_eq!(
+            result,
+            vec![
+                VariableWithoutPosition {
+                    name: "variable".to_string(),
+                    value: VariableValue::Null
+                },
+                VariableWithoutPosition {
+                    name: "list".to_string(),
+                    value: VariableValue::Array(vec![num("1"), VariableValue::Null])
+                }
+            ]
+        );
+    }
+
     #[test]
     fn string_test() {
         let result = gen_vars("variable = \"12345\";".to_string());
@@ -104,7 +192,7 @@ mod parser_test {
                 value: VariableValue::Array(vec![
                     VariableValue::String("abc".to_string()),
                     VariableValue::Char('a'),
-                    VariableValue::Number("12345".to_string()),
+                    num("12345"),
                     VariableValue::Bool(true),
                 ])
             }]
@@ -119,7 +207,7 @@ mod parser_test {
             vec![VariableWithoutPosition {
                 name: "variable".to_string(),
                 value: VariableValue::Array(vec![
-                    VariableValue::Array(vec![VariableValue::Number("123".to_string())]),
+                    VariableValue::Array(vec![num("123")]),
                     VariableValue::Array(vec![VariableValue::String("456".to_string())])
                 ])
             }]
@@ -134,9 +222,7 @@ mod parser_test {
             vec![VariableWithoutPosition {
                 name: "variable".to_string(),
                 value: VariableValue::Array(vec![
-                    VariableValue::Array(vec![VariableValue::Array(vec![VariableValue::Number(
-                        "123".to_string()
-                    )])]),
+                    VariableValue::Array(vec![VariableValue::Array(vec![num("123")])]),
                     VariableValue::Array(vec![VariableValue::Array(vec![VariableValue::String(
                         "456".to_string()
                     )])]),
@@ -153,16 +239,89 @@ mod parser_test {
             vec![VariableWithoutPosition {
                 name: "variable".to_string(),
                 value: VariableValue::Struct(HashMap::from([
+                    ("abc".to_string(), num("12345"),),
+                    ("efg".to_string(), VariableValue::Bool(true)),
+                ]))
+            }]
+        );
+    }
+
+    #[test]
+    fn map_test() {
+        let result = gen_vars(
+            "variable = map { \"tenant-a\": 10, \"tenant b\": map { \"x\": [ true ] } };"
+                .to_string(),
+        );
+        assert_eq!(
+            result,
+            vec![VariableWithoutPosition {
+                name: "variable".to_string(),
+                value: VariableValue::Map(BTreeMap::from([
+                    ("tenant-a".to_string(), num("10"),),
                     (
-                        "abc".to_string(),
-                        VariableValue::Number("12345".to_string()),
+                        "tenant b".to_string(),
+                        VariableValue::Map(BTreeMap::from([(
+                            "x".to_string(),
+                            VariableValue::Array(vec![VariableValue::Bool(true)])
+                        )])),
                     ),
-                    ("efg".to_string(), VariableValue::Bool(true)),
                 ]))
             }]
         );
     }
 
+    #[test]
+    fn keyword_name_test() {
+        let result = gen_vars(
+            "map = 1;\ninclude=2;\nm = map { \"a\": 1 };\ns = struct { map: 3, include: 4 };"
+                .to_string(),
+        );
+        assert_eq!(
+            result,
+            vec![
+                VariableWithoutPosition {
+                    name: "map".to_string(),
+                    value: num("1")
+                },
+                VariableWithoutPosition {
+                    name: "include".to_string(),
+                    value: num("2")
+                },
+                VariableWithoutPosition {
+                    name: "m".to_string(),
+                    value: VariableValue::Map(BTreeMap::from([("a".to_string(), num("1"))]))
+                },
+                VariableWithoutPosition {
+                    name: "s".to_string(),
+                    value: VariableValue::Struct(HashMap::from([
+                        ("map".to_string(), num("3")),
+                        ("include".to_string(), num("4"))
+                    ]))
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn unclosed_string_test() {
+        for code in ["a = \"", "a = '", "a = \"\"\""] {
+            let errors = gen_parsed_vars(code.to_string()).unwrap_err();
+            assert_eq!(
+                errors
+                    .iter()
+                    .map(|err| (err.message.to_owned(), err.position.row, err.position.col))
+                    .collect::<Vec<_>>(),
+                vec![(ErrorContext::IsntClosed("string".to_string()), 1, 5)]
+            );
+        }
+    }
+
+    #[test]
+    fn map_key_error_test() {
+        let result = gen_parsed_vars("variable = map { abc: 1 };".to_string());
+        assert_eq!(result.unwrap_err()[0].message, ErrorContext::InvalidType);
+    }
+
     #[test]
     fn struct_and_array_test() {
         let result = gen_vars(
@@ -181,10 +340,7 @@ mod parser_test {
                                 VariableValue::Array(vec![VariableValue::Bool(true)])
                             )])),
                         ),
-                        (
-                            "hij".to_string(),
-                            VariableValue::Number("12345".to_string())
-                        ),
+                        ("hij".to_string(), num("12345")),
                     ]))
                 },
                 VariableWithoutPosition {
@@ -194,7 +350,7 @@ mod parser_test {
                             "abc".to_string(),
                             VariableValue::Bool(true)
                         )])),
-                        VariableValue::Number("12345".to_string())
+                        num("12345")
                     ])
                 }
             ]
@@ -208,7 +364,7 @@ mod parser_test {
             result,
             vec![VariableWithoutPosition {
                 name: "variable".to_string(),
-                value: VariableValue::Number("12345".to_string())
+                value: num("12345")
             }]
         );
     }
@@ -218,7 +374,10 @@ mod parser_test {
         let result = gen_parsed_vars("variable = \"aiueo';".to_string());
         assert!(result.is_err());
         if let Err(err) = result {
-            assert_eq!(err.message, ErrorContext::InvalidType);
+            assert_eq!(
+                err[0].message,
+                ErrorContext::IsntClosed("string".to_string())
+            );
         }
     }
 
@@ -227,7 +386,10 @@ mod parser_test {
         let result = gen_parsed_vars("variable = 12345; variable = \"12345\";".to_string());
         assert!(result.is_err());
         if let Err(err) = result {
-            assert_eq!(err.message, ErrorContext::Duplicate("variable".to_string()));
+            assert_eq!(
+                err[0].message,
+                ErrorContext::Duplicate("variable".to_string())
+            );
         }
     }
 
@@ -236,7 +398,7 @@ mod parser_test {
         let result = gen_parsed_vars("variable = aiueo;".to_string());
         assert!(result.is_err());
         if let Err(err) = result {
-            assert_eq!(err.message, ErrorContext::InvalidType);
+            assert_eq!(err[0].message, ErrorContext::InvalidType);
         }
     }
 
@@ -245,7 +407,159 @@ mod parser_test {
         let result = gen_parsed_vars("variable = 'char';".to_string());
         assert!(result.is_err());
         if let Err(err) = result {
-            assert_eq!(err.message, ErrorContext::MultipleChar);
+            assert_eq!(err[0].message, ErrorContext::MultipleChar);
+        }
+    }
+
+    fn gen_included_vars(
+        dir_name: &str,
+        files: Vec<(&str, &str)>,
+    ) -> Result<Vec<Variable>, Vec<EnvlError>> {
+        let dir = temp_dir().join(dir_name);
+        for (name, code) in files.iter() {
+            let file_path = dir.join(name);
+            create_dir_all(file_path.parent().unwrap()).unwrap();
+            write(file_path, code).unwrap();
         }
+        let file_path = dir.join(files[0].0).display().to_string();
+        generate_ast_with_root(file_path, files[0].1.to_string(), dir)
+    }
+
+    #[test]
+    fn include_test() {
+        let result = gen_included_vars(
+            "envl_include_test",
+            vec![
+                (".envl", "include \"shared/common.envl\";\nb = 2;"),
+                ("shared/common.envl", "include \"nested.envl\";\na = 1;"),
+                ("shared/nested.envl", "c = 3;"),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(
+            result
+                .iter()
+                .map(|v| (v.name.to_owned(), v.position.file_path.to_owned()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    "c".to_string(),
+                    temp_dir()
+                        .join("envl_include_test/shared/nested.envl")
+                        .display()
+                        .to_string()
+                ),
+                (
+                    "a".to_string(),
+                    temp_dir()
+                        .join("envl_include_test/shared/common.envl")
+                        .display()
+                        .to_string()
+                ),
+                (
+                    "b".to_string(),
+                    temp_dir()
+                        .join("envl_include_test/.envl")
+                        .display()
+                        .to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn include_error_test() {
+        let result = gen_included_vars(
+            "envl_include_cycle_test",
+            vec![
+                (".envl", "include \"a.envl\";"),
+                ("a.envl", "include \".envl\";"),
+            ],
+        );
+        assert_eq!(
+            result.unwrap_err()[0].message,
+            ErrorContext::IncludeCycle(".envl".to_string())
+        );
+
+        let result = gen_included_vars(
+            "envl_include_root_test/project",
+            vec![
+                (".envl", "include \"../outside.envl\";"),
+                ("../outside.envl", "a = 1;"),
+            ],
+        );
+        assert_eq!(
+            result.unwrap_err()[0].message,
+            ErrorContext::OutsideRoot("../outside.envl".to_string())
+        );
+
+        let result = gen_included_vars(
+            "envl_include_duplicate_test",
+            vec![
+                (".envl", "include \"common.envl\";\na = 2;"),
+                ("common.envl", "a = 1;"),
+            ],
+        );
+        let err = &result.unwrap_err()[0];
+        assert_eq!(
+            err.message,
+            ErrorContext::DuplicateAt(
+                "a".to_string(),
+                Position {
+                    file_path: temp_dir()
+                        .join("envl_include_duplicate_test/common.envl")
+                        .display()
+                        .to_string(),
+                    row: 1,
+                    col: 1
+                }
+            )
+        );
+        assert_eq!(err.position.row, 2);
+    }
+
+    #[test]
+    fn error_recovery_test() {
+        let errors = gen_parsed_vars(
+            "a = 1;\nb = ;\nc = [1, 2;\nd = struct { x: 1 };\ne = aiueo;\nf = 2;\na = 3;"
+                .to_string(),
+        )
+        .unwrap_err();
+        let errors = errors
+            .iter()
+            .map(|err| (err.message.to_owned(), err.position.row))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                (ErrorContext::InvalidSyntax, 2),
+                (ErrorContext::IsntClosed("Array".to_string()), 3),
+                (ErrorContext::InvalidType, 5),
+                (ErrorContext::Duplicate("a".to_string()), 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn element_error_recovery_test() {
+        let errors = gen_parsed_vars(
+            "a = struct {\n    x: 1__0,\n    y: [1, aiueo, 3],\n    z: aiueo,\n    w: map { \"k\": 1 }\n};\nb = 1;\nb = 2;"
+                .to_string(),
+        )
+        .unwrap_err();
+        let errors = errors
+            .iter()
+            .map(|err| (err.message.to_owned(), err.position.row))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            vec![
+                (ErrorContext::InvalidNumber("1__0".to_string()), 2),
+                (ErrorContext::InvalidType, 3),
+                (ErrorContext::InvalidType, 4),
+                (ErrorContext::Duplicate("b".to_string()), 8),
+            ]
+        );
     }
 }
@@ -4,22 +4,47 @@ use envl_utils::{
 };
 
 use crate::{
-    misc::{num::is_num, variable::VariableValue},
+    misc::{
+        num::{is_num_like, Number},
+        variable::VariableValue,
+    },
     parser::Parser,
 };
 
 impl Parser {
+    /// Parses the value at `path` of the current variable, remembering where
+    /// a string was written so that later errors can point at it
+    pub fn parse_element_value(
+        &self,
+        value: &str,
+        position: &Position,
+        path: Vec<String>,
+    ) -> Result<VariableValue, EnvlError> {
+        let value = self.parse_value(value, position)?;
+        if let VariableValue::String(_) = value {
+            self.string_positions
+                .borrow_mut()
+                .insert(path, position.clone());
+        }
+        Ok(value)
+    }
+
     pub fn parse_value(
         &self,
         value: &str,
         position: &Position,
     ) -> Result<VariableValue, EnvlError> {
-        if value.starts_with('"') && value.ends_with('"') {
+        if let Some(raw) = value
+            .strip_prefix("r\"")
+            .and_then(|raw| raw.strip_suffix('"'))
+        {
+            Ok(VariableValue::RawString(raw.to_string()))
+        } else if value.len() >= 2 && value.starts_with('"') && value.ends_with('"') {
             let mut str_value = value.to_owned();
             str_value.remove(value.len() - 1);
             str_value.remove(0);
             Ok(VariableValue::String(str_value))
-        } else if value.starts_with('\'') && value.ends_with('\'') {
+        } else if value.len() >= 2 && value.starts_with('\'') && value.ends_with('\'') {
             let mut str_value = value.to_owned();
             str_value.remove(value.len() - 1);
             str_value.remove(0);
@@ -31,10 +56,17 @@ impl Parser {
                     position: position.clone(),
                 })
             }
-        } else if is_num(value.to_owned(), true) {
-            Ok(VariableValue::Number(value.to_owned()))
+        } else if value == "null" {
+            Ok(VariableValue::Null)
+        } else if let Some(n) = Number::parse(value) {
+            Ok(VariableValue::Number(n))
         } else if let Ok(b) = value.parse::<bool>() {
             Ok(VariableValue::Bool(b))
+        } else if is_num_like(value) {
+            Err(EnvlError {
+                message: ErrorContext::InvalidNumber(value.to_string()),
+                position: position.clone(),
+            })
         } else {
             Err(EnvlError {
                 message: ErrorContext::InvalidType,
@@ -0,0 +1,496 @@
+use envl_utils::name::is_valid_variable_name;
+use serde::ser::{self, Serialize};
+
+use crate::misc::error::Error;
+
+pub fn to_string<T: Serialize + ?Sized>(value: &T) -> Result<String, Error> {
+    let mut output = String::new();
+
+    match value.serialize(Serializer)? {
+        Element::Struct(fields) | Element::Map(fields) => {
+            for (name, element) in fields {
+                if let Element::None = element {
+                    continue;
+                }
+                output.push_str(&format!("{} = ", check_name(&name)?));
+                write_element(&element, 0, &mut output);
+                output.push_str(";\n");
+            }
+            Ok(output)
+        }
+        _ => Err(Error::new(
+            "Only structs and maps can be written as .envl".to_string(),
+        )),
+    }
+}
+
+enum Element {
+    None,
+    String(String),
+    Literal(String),
+    Array(Vec<Element>),
+    Struct(Vec<(String, Element)>),
+    Map(Vec<(String, Element)>),
+}
+
+fn quote(value: &str, quote_char: char) -> String {
+    let mut result = String::from(quote_char);
+
+    for c in value.chars() {
+        match c {
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            '\0' => result.push_str("\\0"),
+            '"' | '\'' => {
+                result.push('\\');
+                result.push(c);
+            }
+            c => result.push(c),
+        }
+    }
+    result.push(quote_char);
+
+    result
+}
+
+fn write_element(element: &Element, indent: usize, output: &mut String) {
+    let inner_indent = "    ".repeat(indent + 1);
+    let outer_indent = "    ".repeat(indent);
+
+    match element {
+        Element::None => {}
+        // `${` would be read back as an interpolation
+        Element::String(s) => output.push_str(&quote(&s.replace("${", "$${"), '"')),
+        Element::Literal(l) => output.push_str(l),
+        Element::Array(elements) => {
+            let is_inline = elements
+                .iter()
+                .all(|e| matches!(e, Element::String(_) | Element::Literal(_)));
+
+            if is_inline {
+                output.push('[');
+                for (i, e) in elements.iter().enumerate() {
+                    if i != 0 {
+                        output.push_str(", ");
+                    }
+                    write_element(e, indent, output);
+                }
+                output.push(']');
+            } else {
+                output.push_str("[\n");
+                for (i, e) in elements.iter().enumerate() {
+                    if i != 0 {
+                        output.push_str(",\n");
+                    }
+                    output.push_str(&inner_indent);
+                    write_element(e, indent + 1, output);
+                }
+                output.push_str(&format!("\n{}]", outer_indent));
+            }
+        }
+        Element::Struct(fields) | Element::Map(fields) => {
+            let is_map = matches!(element, Element::Map(_));
+            let keyword = if is_map { "map" } else { "struct" };
+            let fields = fields
+                .iter()
+                .filter(|(_, e)| !matches!(e, Element::None))
+                .collect::<Vec<_>>();
+
+            if fields.is_empty() {
+                output.push_str(&format!("{} {{}}", keyword));
+                return;
+            }
+
+            output.push_str(&format!("{} {{\n", keyword));
+            for (i, (name, e)) in fields.iter().enumerate() {
+                if i != 0 {
+                    output.push_str(",\n");
+                }
+                let name = if is_map {
+                    quote(name, '"')
+                } else {
+                    name.to_string()
+                };
+                output.push_str(&format!("{}{}: ", inner_indent, name));
+                write_element(e, indent + 1, output);
+            }
+            output.push_str(&format!("\n{}}}", outer_indent));
+        }
+    }
+}
+
+fn check_name(name: &str) -> Result<String, Error> {
+    if is_valid_variable_name(name) {
+        Ok(name.to_string())
+    } else {
+        Err(Error::new(format!("Invalid variable name {}", name)))
+    }
+}
+
+fn wrap_variant(variant: Option<&'static str>, element: Element) -> Result<Element, Error> {
+    match variant {
+        Some(variant) => Ok(Element::Struct(vec![(check_name(variant)?, element)])),
+        None => Ok(element),
+    }
+}
+
+struct Serializer;
+
+struct SeqSerializer {
+    elements: Vec<Element>,
+    variant: Option<&'static str>,
+}
+
+struct StructSerializer {
+    fields: Vec<(String, Element)>,
+    next_key: Option<String>,
+    variant: Option<&'static str>,
+    is_map: bool,
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = Element;
+    type Error = Error;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = SeqSerializer;
+    type SerializeMap = StructSerializer;
+    type SerializeStruct = StructSerializer;
+    type SerializeStructVariant = StructSerializer;
+
+    fn serialize_bool(self, v: bool) -> Result<Element, Error> {
+        Ok(Element::Literal(v.to_string()))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Element, Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Element, Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Element, Error> {
+        self.serialize_i64(v as i64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Element, Error> {
+        Ok(Element::Literal(v.to_string()))
+    }
+
+    fn serialize_i128(self, v: i128) -> Result<Element, Error> {
+        Ok(Element::Literal(v.to_string()))
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Element, Error> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Element, Error> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Element, Error> {
+        self.serialize_u64(v as u64)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Element, Error> {
+        Ok(Element::Literal(v.to_string()))
+    }
+
+    fn serialize_u128(self, v: u128) -> Result<Element, Error> {
+        Ok(Element::Literal(v.to_string()))
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Element, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Element, Error> {
+        if v.is_finite() {
+            Ok(Element::Literal(v.to_string()))
+        } else {
+            Err(Error::new(format!("{} can't be written as a number", v)))
+        }
+    }
+
+    fn serialize_char(self, v: char) -> Result<Element, Error> {
+        Ok(Element::Literal(quote(&v.to_string(), '\'')))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Element, Error> {
+        Ok(Element::String(v.to_string()))
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<Element, Error> {
+        Ok(Element::Array(
+            v.iter().map(|b| Element::Literal(b.to_string())).collect(),
+        ))
+    }
+
+    fn serialize_none(self) -> Result<Element, Error> {
+        Ok(Element::None)
+    }
+
+    fn serialize_some<T: Serialize + ?Sized>(self, value: &T) -> Result<Element, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Element, Error> {
+        Ok(Element::None)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Element, Error> {
+        Ok(Element::None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<Element, Error> {
+        Ok(Element::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<Element, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Element, Error> {
+        wrap_variant(Some(variant), value.serialize(self)?)
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<SeqSerializer, Error> {
+        Ok(SeqSerializer {
+            elements: Vec::with_capacity(len.unwrap_or(0)),
+            variant: None,
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<SeqSerializer, Error> {
+        Ok(SeqSerializer {
+            elements: Vec::with_capacity(len),
+            variant: Some(variant),
+        })
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<StructSerializer, Error> {
+        Ok(StructSerializer {
+            fields: Vec::new(),
+            next_key: None,
+            variant: None,
+            is_map: true,
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, _len: usize) -> Result<StructSerializer, Error> {
+        Ok(StructSerializer {
+            fields: Vec::new(),
+            next_key: None,
+            variant: None,
+            is_map: false,
+        })
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<StructSerializer, Error> {
+        Ok(StructSerializer {
+            fields: Vec::new(),
+            next_key: None,
+            variant: Some(variant),
+            is_map: false,
+        })
+    }
+}
+
+impl SeqSerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        match value.serialize(Serializer)? {
+            Element::None => Err(Error::new(
+                "None can't be written as an array element".to_string(),
+            )),
+            element => {
+                self.elements.push(element);
+                Ok(())
+            }
+        }
+    }
+
+    fn finish(self) -> Result<Element, Error> {
+        wrap_variant(self.variant, Element::Array(self.elements))
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Element;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Element, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Element;
+    type Error = Error;
+
+    fn serialize_element<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Element, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Element;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Element, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeTupleVariant for SeqSerializer {
+    type Ok = Element;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Element, Error> {
+        self.finish()
+    }
+}
+
+impl StructSerializer {
+    fn push<T: Serialize + ?Sized>(&mut self, name: &str, value: &T) -> Result<(), Error> {
+        let name = if self.is_map {
+            name.to_string()
+        } else {
+            check_name(name)?
+        };
+        if self.fields.iter().any(|(n, _)| n == &name) {
+            return Err(Error::new(format!("{} is duplicated", name)));
+        }
+        self.fields.push((name, value.serialize(Serializer)?));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Element, Error> {
+        if self.is_map {
+            wrap_variant(self.variant, Element::Map(self.fields))
+        } else {
+            wrap_variant(self.variant, Element::Struct(self.fields))
+        }
+    }
+}
+
+impl ser::SerializeMap for StructSerializer {
+    type Ok = Element;
+    type Error = Error;
+
+    fn serialize_key<T: Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        match key.serialize(Serializer)? {
+            Element::String(s) => {
+                self.next_key = Some(s);
+                Ok(())
+            }
+            _ => Err(Error::new("Map keys must be strings".to_string())),
+        }
+    }
+
+    fn serialize_value<T: Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        match self.next_key.take() {
+            Some(key) => self.push(&key, value),
+            None => Err(Error::new("Map value written before its key".to_string())),
+        }
+    }
+
+    fn end(self) -> Result<Element, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStruct for StructSerializer {
+    type Ok = Element;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Element, Error> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for StructSerializer {
+    type Ok = Element;
+    type Error = Error;
+
+    fn serialize_field<T: Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), Error> {
+        self.push(key, value)
+    }
+
+    fn end(self) -> Result<Element, Error> {
+        self.finish()
+    }
+}
+
+pub mod test;
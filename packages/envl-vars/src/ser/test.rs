@@ -0,0 +1,132 @@
+#[cfg(all(test, feature = "serde"))]
+mod ser_test {
+    use std::collections::BTreeMap;
+
+    use serde::{Deserialize, Serialize};
+
+    use crate::{from_str, to_string};
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Item {
+        a: String,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    enum Mode {
+        Debug,
+        Release,
+    }
+
+    #[derive(Debug, Serialize, Deserialize, PartialEq)]
+    struct Env {
+        a: String,
+        b: i64,
+        c: bool,
+        d: Vec<u32>,
+        e: Item,
+        f: Vec<Item>,
+        g: Option<char>,
+        h: Option<String>,
+        mode: Mode,
+    }
+
+    fn gen_env() -> Env {
+        Env {
+            a: "it's \"quoted\"\n".to_string(),
+            b: -123,
+            c: true,
+            d: vec![1, 2],
+            e: Item {
+                a: "hello".to_string(),
+            },
+            f: vec![Item {
+                a: "hi!".to_string(),
+            }],
+            g: Some('\''),
+            h: None,
+            mode: Mode::Release,
+        }
+    }
+
+    #[test]
+    fn to_string_test() {
+        let result = to_string(&gen_env()).unwrap();
+        assert_eq!(
+            result,
+            "a = \"it\\'s \\\"quoted\\\"\\n\";
+b = -123;
+c = true;
+d = [1, 2];
+e = struct {
+    a: \"hello\"
+};
+f = [
+    struct {
+        a: \"hi!\"
+    }
+];
+g = '\\'';
+mode = \"Release\";
+"
+        );
+    }
+
+    #[test]
+    fn round_trip_test() {
+        let env = gen_env();
+        let result = from_str::<Env>(&to_string(&env).unwrap()).unwrap();
+        assert_eq!(result, env);
+    }
+
+    #[test]
+    fn interpolation_escape_test() {
+        let item = Item {
+            a: "${HOME} and $${x}".to_string(),
+        };
+        let result = to_string(&item).unwrap();
+        assert_eq!(result, "a = \"$${HOME} and $$${x}\";\n");
+        assert_eq!(from_str::<Item>(&result).unwrap(), item);
+    }
+
+    #[test]
+    fn map_test() {
+        let mut map = BTreeMap::new();
+        map.insert("x".to_string(), vec![vec![true], vec![false]]);
+        let result = to_string(&map).unwrap();
+        assert_eq!(result, "x = [\n    [true],\n    [false]\n];\n");
+        assert_eq!(
+            from_str::<BTreeMap<String, Vec<Vec<bool>>>>(&result).unwrap(),
+            map
+        );
+    }
+
+    #[test]
+    fn map_field_test() {
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        struct Limits {
+            limits: BTreeMap<String, u32>,
+        }
+
+        let limits = Limits {
+            limits: BTreeMap::from([("tenant-a".to_string(), 10), ("tenant-b".to_string(), 20)]),
+        };
+        let result = to_string(&limits).unwrap();
+        assert_eq!(
+            result,
+            "limits = map {\n    \"tenant-a\": 10,\n    \"tenant-b\": 20\n};\n"
+        );
+        assert_eq!(from_str::<Limits>(&result).unwrap(), limits);
+    }
+
+    #[test]
+    fn invalid_name_test() {
+        let mut map = BTreeMap::new();
+        map.insert("invalid name".to_string(), 1);
+        assert!(to_string(&map).is_err());
+    }
+
+    #[test]
+    fn top_level_test() {
+        assert!(to_string(&vec![1, 2]).is_err());
+    }
+}
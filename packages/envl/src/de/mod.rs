@@ -0,0 +1,209 @@
+use std::{fmt, vec::IntoIter};
+
+use envl_config::misc::variable::Value;
+use envl_utils::types::Position;
+use serde::de::{
+    self,
+    value::{MapDeserializer, SeqDeserializer, StringDeserializer},
+    DeserializeOwned, DeserializeSeed, Deserializer, IntoDeserializer, MapAccess, Visitor,
+};
+
+use crate::{
+    misc::error::{convert_envl_lib_error, EnvlError, EnvlLibError},
+    VariableHashMap,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error {
+    pub message: String,
+    pub variable: Option<String>,
+    pub position: Option<Position>,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Self {
+            message: msg.to_string(),
+            variable: None,
+            position: None,
+        }
+    }
+}
+
+impl From<Error> for EnvlError {
+    fn from(err: Error) -> Self {
+        EnvlError {
+            position: err.position,
+            variable: err.variable,
+            ..convert_envl_lib_error(EnvlLibError {
+                message: err.message,
+            })
+        }
+    }
+}
+
+pub fn from_vars<T: DeserializeOwned>(data: VariableHashMap) -> Result<T, Box<EnvlError>> {
+    let mut vars = data
+        .into_iter()
+        .map(|(name, var)| {
+            let value = match var.value {
+                Value::Null => var.default_value,
+                v => v,
+            };
+            (name, value, var.position)
+        })
+        .collect::<Vec<_>>();
+    vars.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let deserializer = VarsDeserializer {
+        vars: vars.into_iter(),
+        current: None,
+    };
+
+    T::deserialize(deserializer).map_err(|err| Box::from(EnvlError::from(err)))
+}
+
+pub fn from_value<T: DeserializeOwned>(value: Value) -> Result<T, Error> {
+    T::deserialize(ValueDeserializer(value))
+}
+
+struct VarsDeserializer {
+    vars: IntoIter<(String, Value, Position)>,
+    current: Option<(String, Value, Position)>,
+}
+
+impl<'de> Deserializer<'de> for VarsDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> MapAccess<'de> for VarsDeserializer {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        match self.vars.next() {
+            Some((name, value, position)) => {
+                let key = seed.deserialize(StringDeserializer::<Error>::new(name.to_owned()));
+                self.current = Some((name, value, position));
+                key.map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        match self.current.take() {
+            Some((name, value, position)) => {
+                seed.deserialize(ValueDeserializer(value))
+                    .map_err(|err| Error {
+                        variable: Some(err.variable.unwrap_or(name)),
+                        position: Some(err.position.unwrap_or(position)),
+                        ..err
+                    })
+            }
+            None => Err(de::Error::custom("value is missing")),
+        }
+    }
+}
+
+pub struct ValueDeserializer(pub Value);
+
+impl<'de> IntoDeserializer<'de, Error> for ValueDeserializer {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self::Deserializer {
+        self
+    }
+}
+
+impl<'de> Deserializer<'de> for ValueDeserializer {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Null => visitor.visit_unit(),
+            Value::String(s) => visitor.visit_string(s),
+            Value::Char(c) => visitor.visit_char(c),
+            Value::Float(f) => visitor.visit_f64(f),
+            Value::Int(i) => visitor.visit_i64(i),
+            Value::Uint(u) => visitor.visit_u64(u),
+            Value::Bool(b) => visitor.visit_bool(b),
+            Value::Array(a) => {
+                let mut seq = SeqDeserializer::new(a.into_iter().map(ValueDeserializer));
+                let value = visitor.visit_seq(&mut seq)?;
+                seq.end()?;
+                Ok(value)
+            }
+            Value::Struct(s) => {
+                let mut elements = s.into_iter().collect::<Vec<_>>();
+                elements.sort_by(|a, b| a.0.cmp(&b.0));
+                let mut map = MapDeserializer::new(
+                    elements.into_iter().map(|(n, v)| (n, ValueDeserializer(v))),
+                );
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+            Value::Map(m) => {
+                let mut map =
+                    MapDeserializer::new(m.into_iter().map(|(n, v)| (n, ValueDeserializer(v))));
+                let value = visitor.visit_map(&mut map)?;
+                map.end()?;
+                Ok(value)
+            }
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Error> {
+        match self.0 {
+            Value::String(s) => visitor.visit_enum(StringDeserializer::<Error>::new(s)),
+            _ => Err(de::Error::custom("expected a string for an enum")),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct
+        identifier ignored_any
+    }
+}
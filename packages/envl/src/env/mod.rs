@@ -0,0 +1,111 @@
+use std::collections::{BTreeMap, HashMap};
+
+use envl_config::misc::variable::Value;
+
+use crate::VariableHashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Env<T = Value> {
+    pub vars: HashMap<String, T>,
+}
+
+impl From<VariableHashMap> for Env<Value> {
+    fn from(data: VariableHashMap) -> Self {
+        let vars = data
+            .into_iter()
+            .map(|(name, var)| {
+                let value = match var.value {
+                    Value::Null => var.default_value,
+                    v => v,
+                };
+                (name, value)
+            })
+            .collect::<HashMap<_, _>>();
+
+        Self { vars }
+    }
+}
+
+impl Env<Value> {
+    pub fn get(&self, path: &str) -> Option<&Value> {
+        let mut keys = path.split('.');
+        let mut current = keys.next().and_then(|key| self.vars.get(key));
+
+        for key in keys {
+            current = match current {
+                Some(Value::Struct(elements)) => elements.get(key),
+                Some(Value::Map(elements)) => elements.get(key),
+                Some(Value::Array(elements)) => match key.parse::<usize>() {
+                    Ok(i) => elements.get(i),
+                    Err(_) => None,
+                },
+                _ => None,
+            };
+        }
+
+        current
+    }
+
+    pub fn get_str(&self, path: &str) -> Option<&str> {
+        match self.get(path) {
+            Some(Value::String(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn get_char(&self, path: &str) -> Option<char> {
+        match self.get(path) {
+            Some(Value::Char(c)) => Some(*c),
+            _ => None,
+        }
+    }
+
+    pub fn get_float(&self, path: &str) -> Option<f64> {
+        match self.get(path) {
+            Some(Value::Float(f)) => Some(*f),
+            _ => None,
+        }
+    }
+
+    pub fn get_int(&self, path: &str) -> Option<i64> {
+        match self.get(path) {
+            Some(Value::Int(i)) => Some(*i),
+            _ => None,
+        }
+    }
+
+    pub fn get_uint(&self, path: &str) -> Option<u64> {
+        match self.get(path) {
+            Some(Value::Uint(u)) => Some(*u),
+            _ => None,
+        }
+    }
+
+    pub fn get_bool(&self, path: &str) -> Option<bool> {
+        match self.get(path) {
+            Some(Value::Bool(b)) => Some(*b),
+            _ => None,
+        }
+    }
+
+    pub fn get_array(&self, path: &str) -> Option<&Vec<Value>> {
+        match self.get(path) {
+            Some(Value::Array(a)) => Some(a),
+            _ => None,
+        }
+    }
+
+    pub fn get_struct(&self, path: &str) -> Option<&HashMap<String, Value>> {
+        match self.get(path) {
+            Some(Value::Struct(s)) => Some(s),
+            _ => None,
+        }
+    }
+
+    pub fn get_map(&self, path: &str) -> Option<&BTreeMap<String, Value>> {
+        match self.get(path) {
+            Some(Value::Map(m)) => Some(m),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use envl_config::misc::variable::Value;
+
+use crate::{misc::json::value_to_json, VariableHashMap};
+
+pub const DEFAULT_SEPARATOR: &str = "__";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Dotenv,
+    Shell,
+}
+
+pub fn flatten_vars(data: VariableHashMap, separator: &str) -> Vec<(String, String)> {
+    let mut result = Vec::new();
+
+    for (name, var) in data {
+        let value = match var.value {
+            Value::Null => var.default_value,
+            v => v,
+        };
+        flatten_value(name.to_uppercase(), value, separator, &mut result);
+    }
+
+    result.sort_by(|a, b| a.0.cmp(&b.0));
+    result
+}
+
+fn flatten_value(key: String, value: Value, separator: &str, result: &mut Vec<(String, String)>) {
+    let value = match value {
+        Value::Null => {
+            return;
+        }
+        Value::String(s) => s,
+        Value::Char(c) => c.to_string(),
+        Value::Float(f) => f.to_string(),
+        Value::Int(i) => i.to_string(),
+        Value::Uint(u) => u.to_string(),
+        Value::Bool(b) => b.to_string(),
+        Value::Array(_) | Value::Map(_) => value_to_json(value).to_string(),
+        Value::Struct(elements) => {
+            for (n, v) in elements {
+                flatten_value(
+                    format!("{}{}{}", key, separator, n.to_uppercase()),
+                    v,
+                    separator,
+                    result,
+                );
+            }
+            return;
+        }
+    };
+
+    result.push((key, value));
+}
+
+pub fn export_vars(data: VariableHashMap, format: ExportFormat, separator: &str) -> String {
+    flatten_vars(data, separator)
+        .iter()
+        .map(|(key, value)| match format {
+            ExportFormat::Dotenv => format!("{}={}", key, quote_dotenv(value)),
+            ExportFormat::Shell => format!("export {}={}", key, quote_shell(value)),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn is_plain(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_@%+=:,./-".contains(c))
+}
+
+pub fn quote_dotenv(value: &str) -> String {
+    if is_plain(value) {
+        return value.to_string();
+    }
+
+    let mut result = String::from("\"");
+
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '$' => result.push_str("\\$"),
+            '`' => result.push_str("\\`"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+pub fn quote_shell(value: &str) -> String {
+    if is_plain(value) {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', "'\\''"))
+    }
+}
@@ -0,0 +1,82 @@
+use std::{collections::BTreeMap, io::Error, path::Path};
+
+use crate::{
+    generator::go::{
+        types::parse_v_type,
+        utils::{gen_field_name, gen_struct_type},
+        var::parse_var,
+    },
+    VariableHashMap,
+};
+
+pub mod types;
+pub mod utils;
+pub mod var;
+
+pub fn generate_go_file(data: VariableHashMap, output: String) -> Result<String, Error> {
+    let mut structs = Vec::new();
+    let mut types_hm = BTreeMap::new();
+    let mut value_hm = BTreeMap::new();
+    let mut data = data.into_iter().collect::<Vec<_>>();
+    data.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    for (name, value) in data {
+        let parsed_type = parse_v_type(name.to_owned(), value.to_owned().v_type, &mut structs);
+        types_hm.insert(name.to_owned(), parsed_type);
+
+        match parse_var(name.to_owned(), value) {
+            Ok(v) => {
+                value_hm.insert(name, v);
+            }
+            Err(err) => {
+                return Err(err);
+            }
+        }
+    }
+
+    let env_type = types_hm
+        .iter()
+        .map(|(n, v)| (n.to_owned(), v.to_owned()))
+        .collect::<Vec<_>>();
+    let env_value = value_hm
+        .iter()
+        .map(|(n, v)| format!("{}: {}", gen_field_name(n), v))
+        .collect::<Vec<_>>();
+
+    structs.push(gen_struct_type("Env", env_type));
+
+    let mut result = format!("package {}\n\n", package_name(&output));
+
+    for s in structs {
+        result.push_str(&s);
+        result.push_str("\n\n");
+    }
+
+    result.push_str("func Envl() Env {\n\treturn Env{\n");
+    for value in env_value {
+        result.push_str(&format!("\t\t{},\n", value));
+    }
+    result.push_str("\t}\n}\n\n");
+    result.push_str("func envlPtr[T any](v T) *T {\n\treturn &v\n}");
+
+    Ok(result)
+}
+
+fn package_name(output: &str) -> String {
+    let dir_name = Path::new(output)
+        .parent()
+        .and_then(|p| p.file_name())
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    let name = dir_name
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect::<String>()
+        .to_lowercase();
+
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        "envl".to_string()
+    } else {
+        name
+    }
+}
@@ -0,0 +1,46 @@
+use envl_config::misc::variable::Type;
+
+use crate::generator::go::utils::gen_struct_type;
+
+pub fn parse_v_type(v_name: String, v_type: Type, structs: &mut Vec<String>) -> String {
+    match v_type {
+        Type::Array(boxed_element_type) => {
+            let value = parse_v_type(format!("Array{}", v_name), *boxed_element_type, structs);
+            format!("[]{}", value)
+        }
+        Type::Map(boxed_element_type) => {
+            let value = parse_v_type(format!("Map{}", v_name), *boxed_element_type, structs);
+            format!("map[string]{}", value)
+        }
+        Type::Bool => "bool".to_string(),
+        Type::Char => "rune".to_string(),
+        Type::Float => "float64".to_string(),
+        Type::Int => "int64".to_string(),
+        Type::Null => "any".to_string(),
+        Type::String => "string".to_string(),
+        Type::Option(t) => {
+            let value = parse_v_type(v_name, *t, structs);
+            format!("*{}", value)
+        }
+        Type::Struct(elements) => {
+            let mut elements = elements.into_iter().collect::<Vec<_>>();
+            elements.sort_by(|(a, _), (b, _)| a.cmp(b));
+            let struct_name = format!("Struct{}", v_name);
+            let struct_value = elements
+                .iter()
+                .map(|(n, v)| {
+                    let name = format!("{}{}", struct_name, n);
+                    let v_type = parse_v_type(name.to_owned(), v.to_owned(), structs);
+                    (n.to_owned(), v_type)
+                })
+                .collect::<Vec<_>>();
+
+            structs.push(gen_struct_type(&struct_name, struct_value));
+
+            struct_name
+        }
+        Type::Uint => "uint64".to_string(),
+        Type::Enum(_) => "string".to_string(),
+        Type::Named(_, t) => parse_v_type(v_name, *t, structs),
+    }
+}
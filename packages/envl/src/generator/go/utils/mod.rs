@@ -0,0 +1,64 @@
+use envl_utils::case::{CamelCase, Case, SnakeCase};
+
+pub fn gen_field_name(name: &str) -> String {
+    let camel_case = CamelCase::gen(SnakeCase::parse(name));
+    let mut chars = camel_case.chars();
+
+    match chars.next() {
+        Some(c) => format!("{}{}", c.to_uppercase(), chars.as_str()),
+        None => camel_case,
+    }
+}
+
+pub fn gen_struct_type(name: &str, fields: Vec<(String, String)>) -> String {
+    let mut result = format!("type {} struct {{\n", name);
+
+    for (n, t) in fields {
+        result.push_str(&format!(
+            "\t{} {} `json:\"{}\"`\n",
+            gen_field_name(&n),
+            t,
+            n
+        ));
+    }
+
+    result.push('}');
+    result
+}
+
+pub fn gen_string(value: &str) -> String {
+    let mut result = String::from("\"");
+
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            c => push_escaped(&mut result, c),
+        }
+    }
+
+    result.push('"');
+    result
+}
+
+pub fn gen_rune(value: char) -> String {
+    let mut result = String::from("'");
+
+    match value {
+        '\'' => result.push_str("\\'"),
+        c => push_escaped(&mut result, c),
+    }
+
+    result.push('\'');
+    result
+}
+
+fn push_escaped(result: &mut String, c: char) {
+    match c {
+        '\\' => result.push_str("\\\\"),
+        '\n' => result.push_str("\\n"),
+        '\r' => result.push_str("\\r"),
+        '\t' => result.push_str("\\t"),
+        c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+        c => result.push(c),
+    }
+}
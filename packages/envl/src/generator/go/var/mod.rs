@@ -0,0 +1,13 @@
+use envl_config::misc::variable::Value;
+use std::io::Error;
+
+use crate::{generator::go::var::value::gen_value, VarData};
+
+pub mod value;
+
+pub fn parse_var(name: String, var: VarData) -> Result<String, Error> {
+    match var.value {
+        Value::Null => gen_value(name, var.v_type, var.default_value),
+        _ => gen_value(name, var.v_type, var.value),
+    }
+}
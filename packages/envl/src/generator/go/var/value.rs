@@ -0,0 +1,121 @@
+use std::io::Error;
+
+use envl_config::misc::variable::{Type, Value};
+
+use crate::generator::go::{
+    types::parse_v_type,
+    utils::{gen_field_name, gen_rune, gen_string},
+};
+
+pub fn gen_value(name: String, t: Type, v: Value) -> Result<String, Error> {
+    if let Type::Named(_, boxed_type) = t {
+        return gen_value(name, *boxed_type, v);
+    }
+    if let Type::Option(boxed_type) = &t {
+        return match &v {
+            Value::Null => Ok("nil".to_string()),
+            _ => {
+                let element_type =
+                    parse_v_type(name.to_owned(), *boxed_type.to_owned(), &mut Vec::new());
+                match gen_value(name, *boxed_type.to_owned(), v) {
+                    Ok(r) => Ok(format!("envlPtr[{}]({})", element_type, r)),
+                    Err(err) => Err(err),
+                }
+            }
+        };
+    }
+
+    match &v {
+        Value::Null => Ok("nil".to_string()),
+        Value::String(s) => Ok(gen_string(s)),
+        Value::Char(c) => Ok(gen_rune(*c)),
+        Value::Float(f) => {
+            if f.is_finite() {
+                Ok(format!("{:?}", f))
+            } else {
+                Err(Error::other("Unsupported float value"))
+            }
+        }
+        Value::Int(i) => Ok(i.to_string()),
+        Value::Uint(u) => Ok(u.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        Value::Array(a) => match &t {
+            Type::Array(boxed_type) => {
+                let array_type = parse_v_type(name.to_owned(), t.to_owned(), &mut Vec::new());
+                let mut elements = Vec::new();
+
+                for value in a {
+                    match gen_value(
+                        format!("Array{}", name),
+                        *boxed_type.to_owned(),
+                        value.to_owned(),
+                    ) {
+                        Ok(r) => {
+                            elements.push(r);
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                Ok(format!("{}{{{}}}", array_type, elements.join(", ")))
+            }
+            _ => Err(Error::other("Invalid Type")),
+        },
+        Value::Map(m) => match &t {
+            Type::Map(boxed_type) => {
+                let map_type = parse_v_type(name.to_owned(), t.to_owned(), &mut Vec::new());
+                let mut elements = Vec::new();
+
+                for (key, value) in m {
+                    match gen_value(
+                        format!("Map{}", name),
+                        *boxed_type.to_owned(),
+                        value.to_owned(),
+                    ) {
+                        Ok(r) => {
+                            elements.push(format!("{}: {}", gen_string(key), r));
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                Ok(format!("{}{{{}}}", map_type, elements.join(", ")))
+            }
+            _ => Err(Error::other("Invalid Type")),
+        },
+        Value::Struct(value) => match &t {
+            Type::Struct(struct_type) => {
+                let struct_name = format!("Struct{}", name);
+                let mut elements = Vec::new();
+
+                let mut struct_type = struct_type.iter().collect::<Vec<_>>();
+                struct_type.sort_by_key(|(n, _)| *n);
+
+                for (n, element_type) in struct_type {
+                    let element_name = format!("{}{}", struct_name, n);
+                    if let Some(element) = value.get(n) {
+                        match gen_value(element_name, element_type.to_owned(), element.to_owned()) {
+                            Ok(r) => {
+                                elements.push(format!("{}: {}", gen_field_name(n), r));
+                            }
+                            Err(err) => {
+                                return Err(err);
+                            }
+                        }
+                    } else if let Type::Option(_) = element_type {
+                        elements.push(format!("{}: nil", gen_field_name(n)));
+                    } else {
+                        return Err(Error::other(""));
+                    }
+                }
+
+                Ok(format!("{}{{{}}}", struct_name, elements.join(", ")))
+            }
+            _ => Err(Error::other("Invalid Type")),
+        },
+    }
+}
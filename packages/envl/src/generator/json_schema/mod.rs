@@ -0,0 +1,110 @@
+use std::io::Error;
+
+use envl_config::misc::{
+    config::Config,
+    variable::{Type, Value},
+};
+use serde_json::{json, Map, Value as JsonValue};
+
+use crate::misc::json::value_to_json;
+
+pub const JSON_SCHEMA_DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+pub fn generate_json_schema(config: Config) -> Result<String, Error> {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+
+    for (name, var) in config.vars {
+        let mut schema = parse_v_type(var.v_type.to_owned());
+
+        if var.default_value != Value::Null {
+            schema.insert("default".to_string(), value_to_json(var.default_value));
+        } else if !matches!(var.v_type, Type::Option(_)) {
+            required.push(name.to_owned());
+        }
+
+        properties.insert(name, JsonValue::Object(schema));
+    }
+
+    required.sort();
+
+    let schema = json!({
+        "$schema": JSON_SCHEMA_DRAFT,
+        "type": "object",
+        "properties": properties,
+        "required": required,
+        "additionalProperties": false
+    });
+
+    match serde_json::to_string_pretty(&schema) {
+        Ok(result) => Ok(result),
+        Err(err) => Err(Error::other(err)),
+    }
+}
+
+pub fn parse_v_type(v_type: Type) -> Map<String, JsonValue> {
+    let schema = match v_type {
+        Type::Null => json!({ "type": "null" }),
+        Type::String => json!({ "type": "string" }),
+        Type::Char => json!({ "type": "string", "minLength": 1, "maxLength": 1 }),
+        Type::Float => json!({ "type": "number" }),
+        Type::Int => json!({ "type": "integer" }),
+        Type::Uint => json!({ "type": "integer", "minimum": 0 }),
+        Type::Bool => json!({ "type": "boolean" }),
+        Type::Enum(variants) => json!({ "type": "string", "enum": variants }),
+        Type::Array(boxed_element_type) => json!({
+            "type": "array",
+            "items": parse_v_type(*boxed_element_type)
+        }),
+        Type::Map(boxed_element_type) => json!({
+            "type": "object",
+            "additionalProperties": parse_v_type(*boxed_element_type)
+        }),
+        Type::Struct(elements) => {
+            let mut properties = Map::new();
+            let mut required = Vec::new();
+
+            for (name, element_type) in elements {
+                if !matches!(element_type, Type::Option(_)) {
+                    required.push(name.to_owned());
+                }
+                properties.insert(name, JsonValue::Object(parse_v_type(element_type)));
+            }
+
+            required.sort();
+
+            json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false
+            })
+        }
+        Type::Named(_, t) => {
+            return parse_v_type(*t);
+        }
+        Type::Option(t) => {
+            let mut schema = parse_v_type(*t);
+
+            match schema.get("type") {
+                Some(JsonValue::String(t)) if t != "null" => {
+                    let nullable = json!([t, "null"]);
+                    schema.insert("type".to_string(), nullable);
+                    if let Some(JsonValue::Array(variants)) = schema.get_mut("enum") {
+                        variants.push(JsonValue::Null);
+                    }
+                    return schema;
+                }
+                Some(JsonValue::String(_)) => {
+                    return schema;
+                }
+                _ => json!({ "anyOf": [schema, { "type": "null" }] }),
+            }
+        }
+    };
+
+    match schema {
+        JsonValue::Object(map) => map,
+        _ => Map::new(),
+    }
+}
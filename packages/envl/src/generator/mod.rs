@@ -1,12 +1,28 @@
 use std::io::Error;
 
-use crate::{generator::rust::generate_rust_file, VariableHashMap};
+use crate::{
+    generator::{
+        go::generate_go_file, python::generate_python_file, rust::generate_rust_file,
+        typescript::generate_ts_file,
+    },
+    VariableHashMap,
+};
 
+pub mod go;
+pub mod json_schema;
+pub mod python;
 pub mod rust;
+pub mod typescript;
 
 pub fn generate_file(data: VariableHashMap, output: String) -> Result<String, Error> {
     if output.ends_with(".rs") {
         generate_rust_file(data)
+    } else if output.ends_with(".go") {
+        generate_go_file(data, output)
+    } else if output.ends_with(".py") {
+        generate_python_file(data)
+    } else if output.ends_with(".ts") {
+        generate_ts_file(data)
     } else {
         Err(Error::other("Unsupported file"))
     }
@@ -0,0 +1,60 @@
+use std::{collections::BTreeMap, io::Error};
+
+use crate::{
+    generator::python::{types::parse_v_type, var::parse_var},
+    VariableHashMap,
+};
+
+pub mod types;
+pub mod utils;
+pub mod var;
+
+pub fn generate_python_file(data: VariableHashMap) -> Result<String, Error> {
+    let mut classes = Vec::new();
+    let mut types_hm = BTreeMap::new();
+    let mut value_hm = BTreeMap::new();
+    let mut data = data.into_iter().collect::<Vec<_>>();
+    data.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    for (name, value) in data {
+        let parsed_type = parse_v_type(name.to_owned(), value.to_owned().v_type, &mut classes);
+        types_hm.insert(name.to_owned(), parsed_type);
+
+        match parse_var(name.to_owned(), value) {
+            Ok(v) => {
+                value_hm.insert(name, v);
+            }
+            Err(err) => {
+                return Err(err);
+            }
+        }
+    }
+
+    let env_type = types_hm
+        .iter()
+        .map(|(n, v)| format!("{}: {}", n, v))
+        .collect::<Vec<_>>();
+    let env_value = value_hm
+        .iter()
+        .map(|(n, v)| format!("{}={}", n, v))
+        .collect::<Vec<_>>();
+
+    classes.push(utils::gen_dataclass("Env", env_type));
+
+    let mut result = String::from(
+        "from dataclasses import dataclass\nfrom typing import Literal, Optional\n\n\n",
+    );
+
+    for class in classes {
+        result.push_str(&class);
+        result.push_str("\n\n\n");
+    }
+
+    result.push_str("def envl() -> Env:\n    return Env(\n");
+    for value in env_value {
+        result.push_str(&format!("        {},\n", value));
+    }
+    result.push_str("    )");
+
+    Ok(result)
+}
@@ -0,0 +1,49 @@
+use envl_config::misc::variable::Type;
+
+use crate::generator::python::utils::{gen_dataclass, gen_string};
+
+pub fn parse_v_type(v_name: String, v_type: Type, classes: &mut Vec<String>) -> String {
+    match v_type {
+        Type::Array(boxed_element_type) => {
+            let value = parse_v_type(format!("Array{}", v_name), *boxed_element_type, classes);
+            format!("list[{}]", value)
+        }
+        Type::Map(boxed_element_type) => {
+            let value = parse_v_type(format!("Map{}", v_name), *boxed_element_type, classes);
+            format!("dict[str, {}]", value)
+        }
+        Type::Bool => "bool".to_string(),
+        Type::Char => "str".to_string(),
+        Type::Float => "float".to_string(),
+        Type::Int => "int".to_string(),
+        Type::Null => "None".to_string(),
+        Type::String => "str".to_string(),
+        Type::Option(t) => {
+            let value = parse_v_type(v_name, *t, classes);
+            format!("Optional[{}]", value)
+        }
+        Type::Struct(elements) => {
+            let mut elements = elements.into_iter().collect::<Vec<_>>();
+            elements.sort_by(|(a, _), (b, _)| a.cmp(b));
+            let class_name = format!("Struct{}", v_name);
+            let class_value = elements
+                .iter()
+                .map(|(n, v)| {
+                    let name = format!("{}{}", class_name, n);
+                    let v_type = parse_v_type(name.to_owned(), v.to_owned(), classes);
+                    format!("{}: {}", n, v_type)
+                })
+                .collect::<Vec<_>>();
+
+            classes.push(gen_dataclass(&class_name, class_value));
+
+            class_name
+        }
+        Type::Uint => "int".to_string(),
+        Type::Enum(variants) => {
+            let value = variants.iter().map(|v| gen_string(v)).collect::<Vec<_>>();
+            format!("Literal[{}]", value.join(", "))
+        }
+        Type::Named(_, t) => parse_v_type(v_name, *t, classes),
+    }
+}
@@ -0,0 +1,35 @@
+pub fn gen_dataclass(name: &str, fields: Vec<String>) -> String {
+    let mut result = format!("@dataclass(frozen=True)\nclass {}:\n", name);
+
+    if fields.is_empty() {
+        result.push_str("    pass");
+    } else {
+        let body = fields
+            .iter()
+            .map(|f| format!("    {}", f))
+            .collect::<Vec<_>>()
+            .join("\n");
+        result.push_str(&body);
+    }
+
+    result
+}
+
+pub fn gen_string(value: &str) -> String {
+    let mut result = String::from("\"");
+
+    for c in value.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\r' => result.push_str("\\r"),
+            '\t' => result.push_str("\\t"),
+            c if c.is_control() => result.push_str(&format!("\\u{:04x}", c as u32)),
+            c => result.push(c),
+        }
+    }
+
+    result.push('"');
+    result
+}
@@ -0,0 +1,108 @@
+use std::io::Error;
+
+use envl_config::misc::variable::{Type, Value};
+
+use crate::generator::python::utils::gen_string;
+
+pub fn gen_value(name: String, t: Type, v: Value) -> Result<String, Error> {
+    if let Type::Named(_, boxed_type) = t {
+        return gen_value(name, *boxed_type, v);
+    }
+    if let Type::Option(boxed_type) = &t {
+        return gen_value(name, *boxed_type.to_owned(), v);
+    }
+
+    match &v {
+        Value::Null => Ok("None".to_string()),
+        Value::String(s) => Ok(gen_string(s)),
+        Value::Char(c) => Ok(gen_string(&c.to_string())),
+        Value::Float(f) => {
+            if f.is_nan() {
+                Ok("float(\"nan\")".to_string())
+            } else if f.is_infinite() {
+                Ok(format!("float(\"{}inf\")", if *f < 0.0 { "-" } else { "" }))
+            } else {
+                Ok(format!("{:?}", f))
+            }
+        }
+        Value::Int(i) => Ok(i.to_string()),
+        Value::Uint(u) => Ok(u.to_string()),
+        Value::Bool(b) => Ok(if *b { "True" } else { "False" }.to_string()),
+        Value::Array(a) => match &t {
+            Type::Array(boxed_type) => {
+                let mut elements = Vec::new();
+
+                for value in a {
+                    match gen_value(
+                        format!("Array{}", name),
+                        *boxed_type.to_owned(),
+                        value.to_owned(),
+                    ) {
+                        Ok(r) => {
+                            elements.push(r);
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                Ok(format!("[{}]", elements.join(", ")))
+            }
+            _ => Err(Error::other("Invalid Type")),
+        },
+        Value::Map(m) => match &t {
+            Type::Map(boxed_type) => {
+                let mut elements = Vec::new();
+
+                for (key, value) in m {
+                    match gen_value(
+                        format!("Map{}", name),
+                        *boxed_type.to_owned(),
+                        value.to_owned(),
+                    ) {
+                        Ok(r) => {
+                            elements.push(format!("{}: {}", gen_string(key), r));
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                Ok(format!("{{{}}}", elements.join(", ")))
+            }
+            _ => Err(Error::other("Invalid Type")),
+        },
+        Value::Struct(value) => match &t {
+            Type::Struct(struct_type) => {
+                let class_name = format!("Struct{}", name);
+                let mut elements = Vec::new();
+
+                let mut struct_type = struct_type.iter().collect::<Vec<_>>();
+                struct_type.sort_by_key(|(n, _)| *n);
+
+                for (n, element_type) in struct_type {
+                    let element_name = format!("{}{}", class_name, n);
+                    if let Some(element) = value.get(n) {
+                        match gen_value(element_name, element_type.to_owned(), element.to_owned()) {
+                            Ok(r) => {
+                                elements.push(format!("{}={}", n, r));
+                            }
+                            Err(err) => {
+                                return Err(err);
+                            }
+                        }
+                    } else if let Type::Option(_) = element_type {
+                        elements.push(format!("{}=None", n));
+                    } else {
+                        return Err(Error::other(""));
+                    }
+                }
+
+                Ok(format!("{}({})", class_name, elements.join(", ")))
+            }
+            _ => Err(Error::other("Invalid Type")),
+        },
+    }
+}
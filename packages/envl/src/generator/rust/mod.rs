@@ -1,9 +1,16 @@
-use std::{collections::HashMap, io::Error};
+use std::{
+    collections::{BTreeMap, HashMap},
+    io::Error,
+};
 
 use quote::quote;
 
 use crate::{
-    generator::rust::{types::parse_v_type, utils::struct_derive, var::parse_var},
+    generator::rust::{
+        types::{collect_named_types, gen_named_types, parse_v_type},
+        utils::struct_derive,
+        var::parse_var,
+    },
     VariableHashMap,
 };
 
@@ -17,6 +24,12 @@ pub fn generate_rust_file(data: VariableHashMap) -> Result<String, Error> {
     let mut struct_values = Vec::new();
     let mut types_hm = HashMap::new();
     let mut value_hm = HashMap::new();
+    let mut named_types = BTreeMap::new();
+
+    for value in data.values() {
+        collect_named_types(&value.v_type, &mut named_types);
+    }
+    gen_named_types(named_types, &mut structs);
 
     for (name, value) in data {
         let parsed_type = parse_v_type(name.to_owned(), value.to_owned().v_type, &mut structs);
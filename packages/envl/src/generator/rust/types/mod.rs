@@ -1,3 +1,5 @@
+use std::collections::{BTreeMap, HashMap};
+
 use envl_config::misc::variable::Type;
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -12,6 +14,12 @@ pub fn parse_v_type(v_name: String, v_type: Type, structs: &mut Vec<TokenStream>
                 Vec<#value>
             }
         }
+        Type::Map(boxed_element_type) => {
+            let value = parse_v_type(format!("Map{}", v_name), *boxed_element_type, structs);
+            quote! {
+                std::collections::BTreeMap<String, #value>
+            }
+        }
         Type::Bool => quote! {bool},
         Type::Char => quote! {char},
         Type::Float => quote! {f64},
@@ -24,38 +32,104 @@ pub fn parse_v_type(v_name: String, v_type: Type, structs: &mut Vec<TokenStream>
                 Option<#value>
             }
         }
+        Type::Struct(elements) => gen_struct_type(format!("Struct{}", v_name), elements, structs),
+        Type::Uint => quote! {u64},
+        Type::Enum(variants) => gen_enum_type(format!("Enum{}", v_name), variants, structs),
+        Type::Named(name, _) => name.parse::<TokenStream>().unwrap(),
+    }
+}
+
+fn gen_struct_type(
+    struct_name: String,
+    elements: HashMap<String, Type>,
+    structs: &mut Vec<TokenStream>,
+) -> TokenStream {
+    let s_derive = struct_derive();
+    let struct_name = struct_name.parse::<TokenStream>().unwrap();
+    let struct_value = elements
+        .iter()
+        .map(|(n, v)| {
+            let name = format!("{}{}", struct_name, n);
+            let token_stream_name = n.parse::<TokenStream>().unwrap();
+            let v_type = parse_v_type(name.to_owned(), v.to_owned(), structs);
+            quote! {#token_stream_name: #v_type}
+        })
+        .collect::<Vec<_>>();
+
+    structs.push(quote! {
+        #s_derive
+        #[rustfmt::skip]
+        pub struct #struct_name {
+            #(
+                pub #struct_value,
+            )*
+        }
+    });
+
+    quote! {
+        #struct_name
+    }
+}
+
+fn gen_enum_type(
+    enum_name: String,
+    variants: Vec<String>,
+    structs: &mut Vec<TokenStream>,
+) -> TokenStream {
+    let s_derive = struct_derive();
+    let enum_name = enum_name.parse::<TokenStream>().unwrap();
+    let enum_value = variants
+        .iter()
+        .map(|v| v.parse::<TokenStream>().unwrap())
+        .collect::<Vec<_>>();
+
+    structs.push(quote! {
+        #s_derive
+        #[rustfmt::skip]
+        pub enum #enum_name {
+            #(
+                #enum_value,
+            )*
+        }
+    });
+
+    quote! {
+        #enum_name
+    }
+}
+
+pub fn collect_named_types(v_type: &Type, named_types: &mut BTreeMap<String, Type>) {
+    match v_type {
+        Type::Array(t) | Type::Map(t) | Type::Option(t) => collect_named_types(t, named_types),
         Type::Struct(elements) => {
-            let s_derive = struct_derive();
-            let struct_name = format!("Struct{}", v_name).parse::<TokenStream>().unwrap();
-            let struct_value = elements
-                .iter()
-                .map(|(n, v)| {
-                    let name = match v {
-                        Type::Struct(_) => {
-                            format!("{}{}", struct_name, n)
-                        }
-                        _ => n.to_string(),
-                    };
-                    let token_stream_name = n.parse::<TokenStream>().unwrap();
-                    let v_type = parse_v_type(name.to_owned(), v.to_owned(), structs);
-                    quote! {#token_stream_name: #v_type}
-                })
-                .collect::<Vec<_>>();
-
-            structs.push(quote! {
-                #s_derive
-                #[rustfmt::skip]
-                pub struct #struct_name {
-                    #(
-                        pub #struct_value,
-                    )*
-                }
-            });
+            for t in elements.values() {
+                collect_named_types(t, named_types);
+            }
+        }
+        Type::Named(name, t) if !named_types.contains_key(name) => {
+            named_types.insert(name.to_owned(), *t.to_owned());
+            collect_named_types(t, named_types);
+        }
+        _ => {}
+    }
+}
 
-            quote! {
-                #struct_name
+pub fn gen_named_types(named_types: BTreeMap<String, Type>, structs: &mut Vec<TokenStream>) {
+    for (name, t) in named_types {
+        match t {
+            Type::Struct(elements) => {
+                gen_struct_type(name, elements, structs);
+            }
+            Type::Enum(variants) => {
+                gen_enum_type(name, variants, structs);
+            }
+            t => {
+                let type_name = name.parse::<TokenStream>().unwrap();
+                let v_type = parse_v_type(name, t, structs);
+                structs.push(quote! {
+                    pub type #type_name = #v_type;
+                });
             }
         }
-        Type::Uint => quote! {u64},
     }
 }
@@ -0,0 +1,35 @@
+use std::{collections::BTreeMap, io::Error};
+
+use envl_config::misc::variable::{Type, Value};
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::generator::rust::var::gen_value;
+
+pub fn gen_map(
+    name: String,
+    t: Type,
+    v: BTreeMap<String, Value>,
+    structs: &mut Vec<TokenStream>,
+) -> Result<TokenStream, Error> {
+    let mut map_values = Vec::new();
+
+    for (key, value) in v {
+        match gen_value(name.to_owned(), t.to_owned(), value, structs) {
+            Ok(r) => {
+                map_values.push(quote! { (String::from(#key), #r) });
+            }
+            Err(err) => {
+                return Err(err);
+            }
+        }
+    }
+
+    Ok(quote! {
+        std::collections::BTreeMap::from([
+            #(
+                #map_values,
+            )*
+        ])
+    })
+}
@@ -9,24 +9,17 @@ use crate::generator::rust::var::gen_value;
 
 pub fn gen_struct(
     name: String,
+    struct_type: String,
     t: HashMap<String, Type>,
     v: HashMap<String, Value>,
     structs: &mut Vec<TokenStream>,
 ) -> Result<TokenStream, Error> {
-    let struct_type = format!("Struct{}", name).parse::<TokenStream>().unwrap();
-    let struct_name = SnakeCase::gen(CamelCase::parse(format!("struct{}", name).as_str()))
-        .parse::<TokenStream>()
-        .unwrap();
+    let struct_type = struct_type.parse::<TokenStream>().unwrap();
     let mut struct_values = Vec::new();
 
     for (n, element_type) in t {
         if let Some(value) = v.get(&n) {
-            let element_name = match value {
-                Value::Struct(_) => {
-                    format!("{}{}", struct_type, n.to_owned())
-                }
-                _ => n.to_owned(),
-            };
+            let element_name = format!("{}{}", struct_type, n);
             match gen_value(
                 element_name.to_owned(),
                 element_type.to_owned(),
@@ -53,6 +46,19 @@ pub fn gen_struct(
         })
         .collect::<Vec<_>>();
 
+    let base_name = SnakeCase::gen(CamelCase::parse(format!("struct{}", name).as_str()));
+    let declared_count = structs
+        .iter()
+        .filter(|s| s.to_string().starts_with(&format!("let {} =", base_name)))
+        .count();
+    let struct_name = if declared_count == 0 {
+        base_name
+    } else {
+        format!("{}_{}", base_name, declared_count)
+    }
+    .parse::<TokenStream>()
+    .unwrap();
+
     structs.push(quote! {
         let #struct_name = #struct_type {
             #(
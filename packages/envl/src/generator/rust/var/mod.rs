@@ -5,6 +5,7 @@ use std::io::Error;
 use crate::{generator::rust::var::value::gen_value, VarData};
 
 pub mod array;
+pub mod gen_map;
 pub mod gen_struct;
 pub mod value;
 
@@ -4,7 +4,7 @@ use envl_config::misc::variable::{Type, Value};
 use proc_macro2::{Literal, TokenStream};
 use quote::{quote, ToTokens};
 
-use crate::generator::rust::var::{array::gen_array, gen_struct::gen_struct};
+use crate::generator::rust::var::{array::gen_array, gen_map::gen_map, gen_struct::gen_struct};
 
 pub fn gen_value(
     name: String,
@@ -12,9 +12,25 @@ pub fn gen_value(
     v: Value,
     structs: &mut Vec<TokenStream>,
 ) -> Result<TokenStream, Error> {
-    let result = match &v {
+    match &t {
+        Type::Option(boxed_type) if v != Value::Null => {
+            let token = gen_value(name, *boxed_type.to_owned(), v, structs)?;
+            return Ok(quote! {
+                Some(#token)
+            });
+        }
+        Type::Named(type_name, boxed_type) => {
+            return gen_named_value(name, type_name, *boxed_type.to_owned(), v, structs);
+        }
+        _ => {}
+    }
+
+    match &v {
         Value::Null => Ok(quote! {None}),
-        Value::String(s) => Ok(quote! {String::from(#s)}),
+        Value::String(s) => match &t {
+            Type::Enum(_) => Ok(gen_enum_value(&format!("Enum{}", name), s)),
+            _ => Ok(quote! {String::from(#s)}),
+        },
         Value::Char(c) => Ok(quote! {#c}),
         Value::Float(f) => Ok(Literal::f64_unsuffixed(*f).to_token_stream()),
         Value::Int(i) => Ok(Literal::i64_unsuffixed(*i).to_token_stream()),
@@ -23,7 +39,7 @@ pub fn gen_value(
         Value::Array(a) => match &t {
             Type::Array(boxed_type) => {
                 match gen_array(
-                    format!("Array{}", &name),
+                    format!("Array{}", name),
                     *boxed_type.to_owned(),
                     a.to_vec(),
                     structs,
@@ -34,24 +50,59 @@ pub fn gen_value(
             }
             _ => Err(Error::other("Invalid Type")),
         },
+        Value::Map(m) => match &t {
+            Type::Map(boxed_type) => {
+                match gen_map(
+                    format!("Map{}", name),
+                    *boxed_type.to_owned(),
+                    m.to_owned(),
+                    structs,
+                ) {
+                    Ok(r) => Ok(r),
+                    Err(err) => Err(err),
+                }
+            }
+            _ => Err(Error::other("Invalid Type")),
+        },
         Value::Struct(value) => match &t {
             Type::Struct(struct_type) => {
-                match gen_struct(name, struct_type.to_owned(), value.to_owned(), structs) {
+                match gen_struct(
+                    name.to_owned(),
+                    format!("Struct{}", name),
+                    struct_type.to_owned(),
+                    value.to_owned(),
+                    structs,
+                ) {
                     Ok(r) => Ok(r),
                     Err(err) => Err(err),
                 }
             }
             _ => Err(Error::other("Invalid Type")),
         },
-    };
+    }
+}
 
-    match result {
-        Ok(token) => match t.clone() {
-            Type::Option(_) if v != Value::Null => Ok(quote! {
-                Some(#token)
-            }),
-            _ => Ok(token.to_owned()),
-        },
-        Err(err) => Err(err),
+fn gen_named_value(
+    name: String,
+    type_name: &str,
+    t: Type,
+    v: Value,
+    structs: &mut Vec<TokenStream>,
+) -> Result<TokenStream, Error> {
+    match (t, v) {
+        (Type::Enum(_), Value::String(s)) => Ok(gen_enum_value(type_name, &s)),
+        (Type::Struct(struct_type), Value::Struct(value)) => {
+            gen_struct(name, type_name.to_string(), struct_type, value, structs)
+        }
+        (t, v) => gen_value(name, t, v, structs),
+    }
+}
+
+fn gen_enum_value(enum_name: &str, variant: &str) -> TokenStream {
+    let enum_name = enum_name.parse::<TokenStream>().unwrap();
+    let variant = variant.parse::<TokenStream>().unwrap();
+
+    quote! {
+        #enum_name::#variant
     }
 }
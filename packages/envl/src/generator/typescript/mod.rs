@@ -0,0 +1,68 @@
+use std::{collections::BTreeMap, io::Error};
+
+use envl_codeblock::code_block;
+use proc_macro2::TokenStream;
+
+use crate::{
+    generator::typescript::{types::parse_v_type, var::parse_var},
+    VariableHashMap,
+};
+
+pub mod types;
+pub mod var;
+
+pub fn generate_ts_file(data: VariableHashMap) -> Result<String, Error> {
+    let mut interfaces = Vec::new();
+    let mut types_hm = BTreeMap::new();
+    let mut value_hm = BTreeMap::new();
+    let mut data = data.into_iter().collect::<Vec<_>>();
+    data.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    for (name, value) in data {
+        let parsed_type = parse_v_type(name.to_owned(), value.to_owned().v_type, &mut interfaces);
+        types_hm.insert(name.to_owned(), parsed_type);
+
+        match parse_var(value) {
+            Ok(v) => {
+                value_hm.insert(name, v);
+            }
+            Err(err) => {
+                return Err(err);
+            }
+        }
+    }
+
+    let env_type = types_hm
+        .iter()
+        .map(|(n, v)| {
+            let name = n.parse::<TokenStream>().unwrap();
+            code_block! { #name: #v }
+        })
+        .collect::<Vec<_>>();
+    let env_value = value_hm
+        .iter()
+        .map(|(n, v)| {
+            let name = n.parse::<TokenStream>().unwrap();
+            code_block! { #name: #v }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(code_block! {
+        #(#interfaces)*
+
+        export interface Env {
+            #(
+                #env_type;
+            )*
+        }
+
+        export function envl(): Env {
+            return {
+                #(
+                    #env_value,
+                )*
+            };
+        }
+    }
+    .to_string())
+}
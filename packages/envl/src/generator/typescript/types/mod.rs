@@ -0,0 +1,75 @@
+use envl_codeblock::code_block;
+use envl_config::misc::variable::Type;
+use proc_macro2::{Literal, TokenStream};
+use quote::ToTokens;
+
+pub fn parse_v_type(
+    v_name: String,
+    v_type: Type,
+    interfaces: &mut Vec<TokenStream>,
+) -> TokenStream {
+    match v_type {
+        Type::Array(boxed_element_type) => {
+            let element_type = *boxed_element_type;
+            let value = parse_v_type(
+                format!("Array{}", v_name),
+                element_type.to_owned(),
+                interfaces,
+            );
+            match element_type {
+                Type::Option(_) | Type::Enum(_) => code_block! { (#value)[] },
+                Type::Named(_, t) if matches!(*t, Type::Enum(_)) => code_block! { (#value)[] },
+                _ => code_block! { #value[] },
+            }
+        }
+        Type::Map(boxed_element_type) => {
+            let value = parse_v_type(format!("Map{}", v_name), *boxed_element_type, interfaces);
+            code_block! { Record<string, #value> }
+        }
+        Type::Bool => code_block! {boolean},
+        Type::Char => code_block! {string},
+        Type::Float => code_block! {number},
+        Type::Int => code_block! {number},
+        Type::Null => code_block! {null},
+        Type::String => code_block! {string},
+        Type::Option(t) => {
+            let value = parse_v_type(v_name, *t, interfaces);
+            code_block! { #value | null }
+        }
+        Type::Struct(elements) => {
+            let mut elements = elements.into_iter().collect::<Vec<_>>();
+            elements.sort_by(|(a, _), (b, _)| a.cmp(b));
+            let interface_name = format!("Struct{}", v_name).parse::<TokenStream>().unwrap();
+            let interface_value = elements
+                .iter()
+                .map(|(n, v)| {
+                    let name = format!("{}{}", interface_name, n);
+                    let token_stream_name = n.parse::<TokenStream>().unwrap();
+                    let v_type = parse_v_type(name.to_owned(), v.to_owned(), interfaces);
+                    code_block! { #token_stream_name: #v_type }
+                })
+                .collect::<Vec<_>>();
+
+            interfaces.push(code_block! {
+                export interface #interface_name {
+                    #(
+                        #interface_value;
+                    )*
+                }
+            });
+
+            code_block! {
+                #interface_name
+            }
+        }
+        Type::Uint => code_block! {number},
+        Type::Enum(variants) => {
+            let value = variants
+                .iter()
+                .map(|v| Literal::string(v).to_token_stream())
+                .collect::<Vec<_>>();
+            code_block! { #(#value)|* }
+        }
+        Type::Named(_, t) => parse_v_type(v_name, *t, interfaces),
+    }
+}
@@ -0,0 +1,14 @@
+use envl_config::misc::variable::Value;
+use proc_macro2::TokenStream;
+use std::io::Error;
+
+use crate::{generator::typescript::var::value::gen_value, VarData};
+
+pub mod value;
+
+pub fn parse_var(var: VarData) -> Result<TokenStream, Error> {
+    match var.value {
+        Value::Null => gen_value(var.v_type, var.default_value),
+        _ => gen_value(var.v_type, var.value),
+    }
+}
@@ -0,0 +1,112 @@
+use std::io::Error;
+
+use envl_codeblock::code_block;
+use envl_config::misc::variable::{Type, Value};
+use proc_macro2::{Literal, TokenStream};
+use quote::ToTokens;
+
+pub fn gen_value(t: Type, v: Value) -> Result<TokenStream, Error> {
+    if let Type::Named(_, boxed_type) = t {
+        return gen_value(*boxed_type, v);
+    }
+    if let Type::Option(boxed_type) = &t {
+        return gen_value(*boxed_type.to_owned(), v);
+    }
+
+    match &v {
+        Value::Null => Ok(code_block! {null}),
+        Value::String(s) => Ok(Literal::string(s).to_token_stream()),
+        Value::Char(c) => Ok(Literal::string(&c.to_string()).to_token_stream()),
+        Value::Float(f) => Ok(Literal::f64_unsuffixed(*f).to_token_stream()),
+        Value::Int(i) => Ok(Literal::i64_unsuffixed(*i).to_token_stream()),
+        Value::Uint(u) => Ok(Literal::u64_unsuffixed(*u).to_token_stream()),
+        Value::Bool(b) => Ok(code_block! {#b}),
+        Value::Array(a) => match &t {
+            Type::Array(boxed_type) => {
+                let mut elements = Vec::new();
+
+                for value in a {
+                    match gen_value(*boxed_type.to_owned(), value.to_owned()) {
+                        Ok(r) => {
+                            elements.push(r);
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                Ok(code_block! {
+                    [
+                        #(
+                            #elements,
+                        )*
+                    ]
+                })
+            }
+            _ => Err(Error::other("Invalid Type")),
+        },
+        Value::Map(m) => match &t {
+            Type::Map(boxed_type) => {
+                let mut elements = Vec::new();
+
+                for (key, value) in m {
+                    match gen_value(*boxed_type.to_owned(), value.to_owned()) {
+                        Ok(r) => {
+                            let key = Literal::string(key);
+                            elements.push(code_block! { #key: #r });
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                Ok(code_block! {
+                    {
+                        #(
+                            #elements,
+                        )*
+                    }
+                })
+            }
+            _ => Err(Error::other("Invalid Type")),
+        },
+        Value::Struct(value) => match &t {
+            Type::Struct(struct_type) => {
+                let mut elements = Vec::new();
+
+                let mut struct_type = struct_type.iter().collect::<Vec<_>>();
+                struct_type.sort_by_key(|(n, _)| *n);
+
+                for (n, element_type) in struct_type {
+                    if let Some(element) = value.get(n) {
+                        match gen_value(element_type.to_owned(), element.to_owned()) {
+                            Ok(r) => {
+                                let name = n.parse::<TokenStream>().unwrap();
+                                elements.push(code_block! { #name: #r });
+                            }
+                            Err(err) => {
+                                return Err(err);
+                            }
+                        }
+                    } else if let Type::Option(_) = element_type {
+                        let name = n.parse::<TokenStream>().unwrap();
+                        elements.push(code_block! { #name: null });
+                    } else {
+                        return Err(Error::other(""));
+                    }
+                }
+
+                Ok(code_block! {
+                    {
+                        #(
+                            #elements,
+                        )*
+                    }
+                })
+            }
+            _ => Err(Error::other("Invalid Type")),
+        },
+    }
+}
@@ -1,32 +1,45 @@
 use envl_config::{
     generate_ast as gen_config_ast,
     misc::{
-        config::Config,
+        config::{Config, Var, Vars},
         variable::{Type, Value},
     },
 };
 use envl_utils::types::Position;
-use envl_vars::{
-    generate_ast as gen_vars_ast,
-    misc::variable::{Variable, VariableValue},
+use envl_vars::{generate_ast_with_root as gen_vars_ast, misc::variable::VariableValue};
+use std::{
+    collections::HashMap,
+    env::{current_dir, var},
+    path::{Path, PathBuf},
 };
-use std::{collections::HashMap, env::current_dir, path::PathBuf};
 
 use crate::{
+    env::Env,
     generator::{generate_file, rust::var::value::gen_value},
     misc::{
+        constraints::check_constraints,
         error::{
-            convert_envl_lib_error, convert_envl_vars_error, convert_io_error, EnvlError,
-            EnvlLibError,
+            convert_envl_lib_error, convert_envl_var_error, convert_envl_vars_error,
+            convert_io_error, EnvlError, EnvlLibError,
         },
         filesystem::{read_file, write_file},
-        vars::vars_to_hashmap,
+        vars::{merge_vars, vars_to_hashmap, Value as VarsValue, Variables},
+    },
+    var::{
+        actions::{apply_actions, apply_default_actions},
+        environment::read_env_var,
+        interpolation::{self, lookup_value, resolve_interpolations, value_text, Resolve},
+        parse_var,
     },
-    var::parse_var,
 };
 
+#[cfg(feature = "serde")]
+pub mod de;
+pub mod env;
+pub mod export;
 pub mod generator;
 pub mod misc;
+pub mod test;
 pub mod var;
 
 #[derive(Debug, Clone)]
@@ -34,14 +47,13 @@ pub struct VarData {
     pub value: Value,
     pub v_type: Type,
     pub default_value: Value,
-    pub actions_value: Value,
     pub basic_value: Option<VariableValue>,
     pub position: Position,
 }
 
 pub type VariableHashMap = HashMap<String, VarData>;
 
-pub fn load_envl(output: String) -> Result<(), Box<EnvlError>> {
+pub fn load_envl(output: String) -> Result<(), Vec<EnvlError>> {
     match current_dir() {
         Ok(current_dir_path) => {
             let config_file_path = current_dir_path.join(".envlconf").display().to_string();
@@ -51,24 +63,59 @@ pub fn load_envl(output: String) -> Result<(), Box<EnvlError>> {
                         current_dir_path.to_owned(),
                         config_file_path.to_owned(),
                         code,
+                        None,
                     ) {
                         Ok(hm) => match generate_file(hm, output.to_owned()) {
                             Ok(result) => {
                                 if let Err(err) = write_file(output, result) {
-                                    Err(Box::from(convert_io_error(err)))
+                                    Err(vec![convert_io_error(err)])
                                 } else {
                                     Ok(())
                                 }
                             }
-                            Err(err) => Err(Box::from(convert_io_error(err))),
+                            Err(err) => Err(vec![convert_io_error(err)]),
                         },
-                        Err(err) => Err(err),
+                        Err(errors) => Err(errors),
                     }
                 }
-                Err(err) => Err(err),
+                Err(err) => Err(vec![*err]),
             }
         }
-        Err(err) => Err(Box::from(convert_io_error(err))),
+        Err(err) => Err(vec![convert_io_error(err)]),
+    }
+}
+
+pub fn load(config_file_path: String) -> Result<Env<Value>, Vec<EnvlError>> {
+    match load_vars(config_file_path) {
+        Ok(hm) => Ok(Env::from(hm)),
+        Err(errors) => Err(errors),
+    }
+}
+
+#[cfg(feature = "serde")]
+pub fn load_as<T: serde::de::DeserializeOwned>(
+    config_file_path: String,
+) -> Result<T, Vec<EnvlError>> {
+    match load_vars(config_file_path) {
+        Ok(hm) => de::from_vars(hm).map_err(|err| vec![*err]),
+        Err(errors) => Err(errors),
+    }
+}
+
+fn load_vars(config_file_path: String) -> Result<VariableHashMap, Vec<EnvlError>> {
+    let current_dir_path = match Path::new(&config_file_path).parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+        _ => match current_dir() {
+            Ok(current_dir_path) => current_dir_path,
+            Err(err) => {
+                return Err(vec![convert_io_error(err)]);
+            }
+        },
+    };
+
+    match read_file(config_file_path.to_owned()) {
+        Ok(code) => load_envl_core(current_dir_path, config_file_path, code, None),
+        Err(err) => Err(vec![*err]),
     }
 }
 
@@ -76,80 +123,267 @@ pub fn load_envl_core(
     current_dir: PathBuf,
     config_file_path: String,
     code: String,
-) -> Result<VariableHashMap, Box<EnvlError>> {
-    match load_files(current_dir, config_file_path, code) {
-        Ok((vars, config)) => {
-            let vars_hm = vars_to_hashmap(vars);
-            let mut result = HashMap::new();
-
-            for (name, value) in config.vars {
-                if let Some(v) = vars_hm.get(&name) {
-                    match parse_var(value.v_type.clone(), v.value.clone()) {
-                        Ok(var) => {
-                            result.insert(
-                                name,
-                                VarData {
-                                    value: var,
-                                    v_type: value.v_type.clone(),
-                                    default_value: value.default_value,
-                                    actions_value: value.actions_value,
-                                    basic_value: Some(v.value.clone()),
-                                    position: v.position.clone(),
-                                },
-                            );
-                        }
-                        Err(err) => {
-                            return Err(err);
-                        }
-                    }
-                } else {
-                    result.insert(
-                        name,
-                        VarData {
-                            value: Value::Null,
-                            v_type: value.v_type,
-                            default_value: value.default_value,
-                            actions_value: value.actions_value,
-                            basic_value: None,
-                            position: value.position,
-                        },
-                    );
-                }
+    profile: Option<String>,
+) -> Result<VariableHashMap, Vec<EnvlError>> {
+    match load_files(current_dir, config_file_path, code, profile) {
+        Ok((vars_hm, config)) => {
+            let mut loader = VarLoader {
+                config_vars: &config.vars,
+                vars_hm: &vars_hm,
+                loaded: HashMap::new(),
+                stack: Vec::new(),
+                errors: Vec::new(),
+            };
+            let mut names = config.vars.keys().collect::<Vec<_>>();
+            names.sort();
+            for name in names {
+                loader.load(name);
             }
 
-            if let Err(err) = check_envl_vars(result.to_owned()) {
-                Err(Box::from(convert_envl_lib_error(err)))
-            } else {
+            let mut errors = loader.errors;
+            let result = loader
+                .loaded
+                .into_iter()
+                .filter_map(|(name, var)| var.map(|var| (name, var)))
+                .collect::<HashMap<_, _>>();
+
+            if let Err(errs) = check_envl_vars(result.to_owned()) {
+                errors.extend(errs);
+            }
+
+            if errors.is_empty() {
                 Ok(result)
+            } else {
+                errors.sort_by_key(|err| {
+                    err.position
+                        .as_ref()
+                        .map(|p| (p.file_path.to_owned(), p.row, p.col))
+                });
+                Err(errors)
             }
         }
-        Err(err) => Err(err),
+        Err(errors) => Err(errors),
     }
 }
 
-pub fn check_envl_vars(hm: HashMap<String, VarData>) -> Result<(), EnvlLibError> {
-    for (name, value) in hm {
-        if value.value == Value::Null {
-            match &value.default_value {
-                Value::Null => match &value.v_type {
-                    Type::Option(_) => {}
-                    _ => {
-                        return Err(EnvlLibError {
-                            message: "Invalid Type".to_string(),
-                        });
-                    }
+pub fn check_envl(
+    current_dir: PathBuf,
+    config_file_path: String,
+    code: String,
+    profile: Option<String>,
+) -> Vec<EnvlError> {
+    match load_envl_core(current_dir, config_file_path, code, profile) {
+        Ok(_) => Vec::new(),
+        Err(errors) => errors,
+    }
+}
+
+/// Loads vars in dependency order, so that `${path}` reads the final value of
+/// the var it points at, after env, defaults and actions
+struct VarLoader<'a> {
+    config_vars: &'a Vars,
+    vars_hm: &'a Variables,
+    /// `None` for a var that failed to load
+    loaded: HashMap<String, Option<VarData>>,
+    stack: Vec<String>,
+    errors: Vec<EnvlError>,
+}
+
+impl VarLoader<'_> {
+    fn load(&mut self, name: &str) -> Option<VarData> {
+        if let Some(var) = self.loaded.get(name) {
+            return var.clone();
+        }
+        let value = self.config_vars.get(name)?.clone();
+        let vars_hm = self.vars_hm;
+
+        self.stack.push(name.to_string());
+        let result = load_var(name.to_string(), value, vars_hm.get(name), &mut |path| {
+            self.resolve(path)
+        });
+        self.stack.pop();
+
+        let var = match result {
+            Ok(var) => Some(var),
+            Err(errors) => {
+                self.errors.extend(errors);
+                None
+            }
+        };
+        self.loaded.insert(name.to_string(), var.clone());
+        var
+    }
+
+    fn resolve(&mut self, path: &[String]) -> Result<String, Option<String>> {
+        let name = &path[0];
+        if let Some(start) = self.stack.iter().position(|n| n == name) {
+            let mut cycle = self.stack[start..].to_vec();
+            cycle.push(name.to_owned());
+            return Err(Some(format!("Circular reference {}", cycle.join(" -> "))));
+        }
+        if !self.config_vars.contains_key(name) {
+            return Err(Some(format!("{} isn't defined", name)));
+        }
+
+        let var = self.load(name).ok_or(None)?;
+        let value = if var.value == Value::Null {
+            &var.default_value
+        } else {
+            &var.value
+        };
+        match lookup_value(value, &path[1..]) {
+            Some(value) => Ok(value_text(path, value)?),
+            None => Err(Some(format!(
+                "{} isn't defined",
+                interpolation::display_path(path)
+            ))),
+        }
+    }
+}
+
+fn load_var(
+    name: String,
+    value: Var,
+    envl_value: Option<&VarsValue>,
+    resolve: &mut Resolve<'_>,
+) -> Result<VarData, Vec<EnvlError>> {
+    let default_value = match apply_default_actions(value.default_value.clone(), &value.actions) {
+        Ok(default_value) => default_value,
+        Err(err) => {
+            return Err(vec![convert_envl_var_error(*err, name, value.position)]);
+        }
+    };
+    let env_value = match &value.env {
+        Some(env) => match read_env_var(env, &value.v_type) {
+            Ok(env_value) => env_value.map(|v| (v, value.position.clone())),
+            Err(err) => {
+                return Err(vec![convert_envl_var_error(*err, name, value.position)]);
+            }
+        },
+        None => None,
+    };
+    let source = match (env_value, envl_value) {
+        (Some(env_value), _) => Some(env_value),
+        (None, Some(envl_value)) => Some((
+            resolve_interpolations(&name, envl_value, resolve)?,
+            envl_value.position.clone(),
+        )),
+        (None, None) => None,
+    };
+
+    let var = if let Some((basic_value, position)) = source {
+        match apply_actions(basic_value.clone(), &value.actions)
+            .and_then(|v| parse_var(value.v_type.clone(), v))
+        {
+            Ok(var) => VarData {
+                // An explicit null disables the default as well
+                default_value: if var == Value::Null {
+                    Value::Null
+                } else {
+                    default_value
                 },
-                v => {
-                    if gen_value(name, value.v_type.to_owned(), v.to_owned(), &mut Vec::new())
-                        .is_err()
-                    {
-                        return Err(EnvlLibError {
-                            message: "Invalid Type".to_string(),
-                        });
-                    }
-                }
+                value: var,
+                v_type: value.v_type.clone(),
+                basic_value: Some(basic_value),
+                position,
+            },
+            Err(err) => {
+                return Err(vec![with_declaration(
+                    convert_envl_var_error(*err, name, position),
+                    &value.position,
+                )]);
             }
         }
+    } else {
+        VarData {
+            value: Value::Null,
+            v_type: value.v_type,
+            default_value,
+            basic_value: None,
+            position: value.position.clone(),
+        }
+    };
+
+    let resolved = if var.value == Value::Null {
+        &var.default_value
+    } else {
+        &var.value
+    };
+    if let Err(message) = check_constraints(resolved, &value.constraints) {
+        return Err(vec![with_declaration(
+            convert_envl_var_error(
+                convert_envl_lib_error(EnvlLibError { message }),
+                name,
+                var.position,
+            ),
+            &value.position,
+        )]);
+    }
+
+    Ok(var)
+}
+
+/// Points back at the `.envlconf` declaration when the error is reported
+/// somewhere else
+fn with_declaration(err: EnvlError, declared_at: &Position) -> EnvlError {
+    match &err.position {
+        Some(position) if position != declared_at => {
+            err.with_secondary_label(declared_at.clone(), "declared here".to_string())
+        }
+        _ => err,
+    }
+}
+
+pub fn check_envl_vars(hm: HashMap<String, VarData>) -> Result<(), Vec<EnvlError>> {
+    let mut errors = Vec::new();
+
+    for (name, value) in hm {
+        if let Err(err) = check_envl_var(name, value) {
+            errors.push(*err);
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+pub fn check_envl_var(name: String, value: VarData) -> Result<(), Box<EnvlError>> {
+    if value.value == Value::Null {
+        let err = match &value.default_value {
+            Value::Null => match &value.v_type {
+                Type::Option(_) => {
+                    return Ok(());
+                }
+                _ => convert_envl_lib_error(EnvlLibError {
+                    message: "Value is required".to_string(),
+                })
+                .with_help(format!(
+                    "assign {} in .envl or give it a default value",
+                    name
+                )),
+            },
+            v => {
+                if gen_value(
+                    name.to_owned(),
+                    value.v_type.to_owned(),
+                    v.to_owned(),
+                    &mut Vec::new(),
+                )
+                .is_ok()
+                {
+                    return Ok(());
+                }
+                convert_envl_lib_error(EnvlLibError {
+                    message: "Invalid Type".to_string(),
+                })
+                .with_label(format!("the default value isn't {}", value.v_type))
+            }
+        };
+
+        return Err(Box::from(convert_envl_var_error(err, name, value.position)));
     }
 
     Ok(())
@@ -159,7 +393,8 @@ pub fn load_files(
     current_dir: PathBuf,
     config_file_path: String,
     code: String,
-) -> Result<(Vec<Variable>, Config), Box<EnvlError>> {
+    profile: Option<String>,
+) -> Result<(Variables, Config), Vec<EnvlError>> {
     match gen_config_ast(config_file_path.clone(), code.clone()) {
         Ok(config) => {
             let file_path = if let Some(ref file_path) = config.settings.envl_file_path {
@@ -167,14 +402,53 @@ pub fn load_files(
             } else {
                 current_dir.join(".envl").display().to_string()
             };
-            match read_file(file_path.to_owned()) {
-                Ok(code) => match gen_vars_ast(file_path, code) {
-                    Ok(vars) => Ok((vars, config)),
-                    Err(err) => Err(Box::from(convert_envl_vars_error(err))),
-                },
-                Err(err) => Err(err),
+            let profile = profile
+                .or_else(|| var("ENVL_PROFILE").ok())
+                .filter(|profile| !profile.is_empty());
+
+            let mut vars_hm = match load_vars_file(file_path.to_owned(), &current_dir) {
+                Ok(vars_hm) => vars_hm,
+                Err(errors) => {
+                    return Err(errors);
+                }
+            };
+            let mut overlays = Vec::new();
+            if let Some(profile) = profile {
+                let overlay = format!("{}.{}", file_path, profile);
+                // Unlike .envl.local, a profile overlay was asked for by name
+                if !Path::new(&overlay).is_file() {
+                    return Err(vec![convert_envl_lib_error(EnvlLibError {
+                        message: format!("Profile {} has no overlay file {}", profile, overlay),
+                    })]);
+                }
+                overlays.push(overlay);
+            }
+            let local_overlay = format!("{}.local", file_path);
+            if Path::new(&local_overlay).is_file() {
+                overlays.push(local_overlay);
+            }
+
+            for overlay in overlays {
+                match load_vars_file(overlay, &current_dir) {
+                    Ok(overlay_hm) => merge_vars(&mut vars_hm, overlay_hm),
+                    Err(errors) => {
+                        return Err(errors);
+                    }
+                }
             }
+
+            Ok((vars_hm, config))
         }
-        Err(err) => Err(Box::from(convert_envl_vars_error(err))),
+        Err(errors) => Err(errors.into_iter().map(convert_envl_vars_error).collect()),
+    }
+}
+
+fn load_vars_file(file_path: String, root: &Path) -> Result<Variables, Vec<EnvlError>> {
+    match read_file(file_path.to_owned()) {
+        Ok(code) => match gen_vars_ast(file_path, code, root.to_path_buf()) {
+            Ok(vars) => Ok(vars_to_hashmap(vars)),
+            Err(errors) => Err(errors.into_iter().map(convert_envl_vars_error).collect()),
+        },
+        Err(err) => Err(vec![*err]),
     }
 }
@@ -0,0 +1,102 @@
+use std::cmp::Ordering;
+
+use envl_config::misc::{config::Constraints, variable::Value};
+use regex::Regex;
+
+use crate::misc::json::value_to_json;
+
+fn as_f64(value: &Value) -> Option<f64> {
+    match value {
+        Value::Float(f) => Some(*f),
+        Value::Int(i) => Some(*i as f64),
+        Value::Uint(u) => Some(*u as f64),
+        _ => None,
+    }
+}
+
+fn as_i128(value: &Value) -> Option<i128> {
+    match value {
+        Value::Int(i) => Some(i128::from(*i)),
+        Value::Uint(u) => Some(i128::from(*u)),
+        _ => None,
+    }
+}
+
+/// Compares integers exactly and falls back to floats for everything else
+fn compare(value: &Value, bound: &Value) -> Option<Ordering> {
+    match (as_i128(value), as_i128(bound)) {
+        (Some(value), Some(bound)) => Some(value.cmp(&bound)),
+        _ => as_f64(value)?.partial_cmp(&as_f64(bound)?),
+    }
+}
+
+fn display_value(value: &Value) -> String {
+    value_to_json(value.to_owned()).to_string()
+}
+
+fn value_len(value: &Value) -> Option<u64> {
+    match value {
+        Value::String(s) => Some(s.chars().count() as u64),
+        Value::Array(elements) => Some(elements.len() as u64),
+        Value::Map(elements) => Some(elements.len() as u64),
+        _ => None,
+    }
+}
+
+pub fn check_constraints(value: &Value, constraints: &Constraints) -> Result<(), String> {
+    if *value == Value::Null {
+        return Ok(());
+    }
+
+    if let Some(min) = &constraints.min {
+        if compare(value, min) == Some(Ordering::Less) {
+            return Err(format!("Value must be at least {}", display_value(min)));
+        }
+    }
+    if let Some(max) = &constraints.max {
+        if compare(value, max) == Some(Ordering::Greater) {
+            return Err(format!("Value must be at most {}", display_value(max)));
+        }
+    }
+
+    if let Some(len) = value_len(value) {
+        if let Some(min_len) = constraints.min_len {
+            if len < min_len {
+                return Err(format!("Length must be at least {}", min_len));
+            }
+        }
+        if let Some(max_len) = constraints.max_len {
+            if len > max_len {
+                return Err(format!("Length must be at most {}", max_len));
+            }
+        }
+    }
+
+    if let (Some(pattern), Value::String(s)) = (&constraints.pattern, value) {
+        match Regex::new(pattern) {
+            Ok(re) => {
+                if !re.is_match(s) {
+                    return Err(format!("Value doesn't match the pattern {}", pattern));
+                }
+            }
+            Err(_) => {
+                return Err(format!("Invalid pattern {}", pattern));
+            }
+        }
+    }
+
+    if let Some(one_of) = &constraints.one_of {
+        if !one_of.contains(value) {
+            return Err(format!(
+                "Value must be one of {}",
+                one_of
+                    .iter()
+                    .map(display_value)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ));
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,141 @@
+use std::{collections::HashMap, fs::read_to_string};
+
+use envl_utils::types::Position;
+
+use crate::misc::error::EnvlError;
+
+struct Snippet<'a> {
+    position: &'a Position,
+    marker: char,
+    label: Option<&'a str>,
+}
+
+/// Renders an error with the source lines it points at, e.g.
+///
+/// ```text
+/// error: Invalid type in a
+///  --> .envl:1:1
+///   |
+/// 1 | a = "123";
+///   | ^ expected int
+///   |
+///  ::: .envlconf:2:8
+///   |
+/// 2 |     a: int
+///   |        --- declared here
+/// ```
+pub fn render(err: &EnvlError) -> String {
+    let mut lines = vec![match &err.variable {
+        Some(variable) => format!("error: {} in {}", err.message, variable),
+        None => format!("error: {}", err.message),
+    }];
+
+    let mut snippets = Vec::new();
+    if let Some(position) = &err.position {
+        snippets.push(Snippet {
+            position,
+            marker: '^',
+            label: err.label.as_deref(),
+        });
+    }
+    for label in &err.labels {
+        snippets.push(Snippet {
+            position: &label.position,
+            marker: '-',
+            label: Some(&label.message),
+        });
+    }
+
+    let width = snippets
+        .iter()
+        .map(|snippet| snippet.position.row.to_string().len())
+        .max()
+        .unwrap_or_default();
+    let gutter = " ".repeat(width);
+    let mut sources = HashMap::new();
+
+    for (i, snippet) in snippets.iter().enumerate() {
+        let arrow = if i == 0 { "-->" } else { ":::" };
+        if i > 0 {
+            lines.push(format!("{} |", gutter));
+        }
+        lines.push(format!("{}{} {}", gutter, arrow, snippet.position));
+
+        let source = sources
+            .entry(snippet.position.file_path.clone())
+            .or_insert_with(|| read_to_string(&snippet.position.file_path).ok());
+        let line = source.as_ref().and_then(|source| {
+            snippet
+                .position
+                .row
+                .checked_sub(1)
+                .and_then(|row| source.lines().nth(row))
+        });
+        let Some(line) = line else {
+            continue;
+        };
+
+        let start = snippet.position.col.saturating_sub(1);
+        let indent = line
+            .chars()
+            .take(start)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect::<String>();
+        let rest = line.chars().skip(start).collect::<String>();
+        let mut marker = format!(
+            "{}{}",
+            indent,
+            snippet.marker.to_string().repeat(token_len(&rest))
+        );
+        if let Some(label) = snippet.label {
+            marker = format!("{} {}", marker, label);
+        }
+
+        lines.push(format!("{} |", gutter));
+        lines.push(format!("{:>width$} | {}", snippet.position.row, line));
+        lines.push(format!("{} | {}", gutter, marker));
+    }
+
+    if let Some(help) = &err.help {
+        if !snippets.is_empty() {
+            lines.push(format!("{} |", gutter));
+        }
+        lines.push(format!("{} = help: {}", gutter, help));
+    }
+
+    lines.join("\n")
+}
+
+/// Length of the token at the start of `rest`, used for the underline
+fn token_len(rest: &str) -> usize {
+    let mut chars = rest.chars();
+
+    match chars.next() {
+        None => 1,
+        Some(_) if rest.starts_with("\"\"\"") => rest.chars().count(),
+        Some(quote @ ('"' | '\'')) => {
+            let mut len = 1;
+            let mut is_escape = false;
+            for c in chars {
+                len += 1;
+                if !is_escape && c == quote {
+                    break;
+                }
+                is_escape = !is_escape && c == '\\';
+            }
+            len
+        }
+        Some(c) if is_punctuation(c) => 1,
+        Some(_) => rest
+            .chars()
+            .take_while(|c| !c.is_whitespace() && !is_punctuation(*c))
+            .count(),
+    }
+}
+
+fn is_punctuation(c: char) -> bool {
+    matches!(
+        c,
+        '[' | ']' | '{' | '}' | '(' | ')' | '<' | '>' | ':' | ';' | ',' | '='
+    )
+}
@@ -1,6 +1,9 @@
-use std::io::Error;
+use std::{fmt, io::Error};
 
-use envl_utils::{error::EnvlError as EnvlVarsError, types::Position};
+use envl_utils::{
+    error::{EnvlError as EnvlVarsError, ErrorContext},
+    types::Position,
+};
 
 #[derive(Debug, Clone)]
 pub struct EnvlLibError {
@@ -14,18 +17,80 @@ pub enum ErrorKind {
     Lib(EnvlLibError),
 }
 
+#[derive(Debug, Clone)]
+pub struct Label {
+    pub position: Position,
+    pub message: String,
+}
+
 #[derive(Debug)]
 pub struct EnvlError {
     pub message: String,
     pub position: Option<Position>,
+    pub variable: Option<String>,
     pub kind: ErrorKind,
+    /// Shown under the source at `position`
+    pub label: Option<String>,
+    /// Other places in the sources that explain the error
+    pub labels: Vec<Label>,
+    pub help: Option<String>,
+}
+
+impl EnvlError {
+    pub fn with_label(self, label: String) -> Self {
+        Self {
+            label: Some(label),
+            ..self
+        }
+    }
+
+    pub fn with_secondary_label(mut self, position: Position, message: String) -> Self {
+        self.labels.push(Label { position, message });
+        self
+    }
+
+    pub fn with_help(self, help: String) -> Self {
+        Self {
+            help: Some(help),
+            ..self
+        }
+    }
+}
+
+impl fmt::Display for EnvlError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Error: {}", self.message)?;
+        if let Some(variable) = &self.variable {
+            write!(f, " in {}", variable)?;
+        }
+        if let Some(position) = &self.position {
+            write!(
+                f,
+                " (at {}:{}:{})",
+                position.file_path, position.row, position.col
+            )?;
+        }
+        Ok(())
+    }
 }
 
 pub fn convert_envl_vars_error(err: EnvlVarsError) -> EnvlError {
+    let labels = match &err.message {
+        ErrorContext::DuplicateAt(_, first) => vec![Label {
+            position: first.clone(),
+            message: "first defined here".to_string(),
+        }],
+        _ => Vec::new(),
+    };
+
     EnvlError {
         message: err.message.to_string(),
-        position: None,
+        position: Some(err.position.clone()),
+        variable: None,
         kind: ErrorKind::Vars(err),
+        label: None,
+        labels,
+        help: None,
     }
 }
 
@@ -33,7 +98,11 @@ pub fn convert_io_error(err: Error) -> EnvlError {
     EnvlError {
         message: err.to_string().clone(),
         position: None,
+        variable: None,
         kind: ErrorKind::Io(err),
+        label: None,
+        labels: Vec::new(),
+        help: None,
     }
 }
 
@@ -41,6 +110,18 @@ pub fn convert_envl_lib_error(err: EnvlLibError) -> EnvlError {
     EnvlError {
         message: err.message.to_string(),
         position: None,
+        variable: None,
         kind: ErrorKind::Lib(err),
+        label: None,
+        labels: Vec::new(),
+        help: None,
+    }
+}
+
+pub fn convert_envl_var_error(err: EnvlError, name: String, position: Position) -> EnvlError {
+    EnvlError {
+        position: Some(err.position.unwrap_or(position)),
+        variable: Some(err.variable.unwrap_or(name)),
+        ..err
     }
 }
@@ -0,0 +1,32 @@
+use envl_config::misc::variable::Value;
+use serde_json::{Number, Value as JsonValue};
+
+pub fn value_to_json(value: Value) -> JsonValue {
+    match value {
+        Value::Null => JsonValue::Null,
+        Value::String(s) => JsonValue::String(s),
+        Value::Char(c) => JsonValue::String(c.to_string()),
+        Value::Float(f) => match Number::from_f64(f) {
+            Some(n) => JsonValue::Number(n),
+            None => JsonValue::Null,
+        },
+        Value::Int(i) => JsonValue::from(i),
+        Value::Uint(u) => JsonValue::from(u),
+        Value::Bool(b) => JsonValue::Bool(b),
+        Value::Array(elements) => {
+            JsonValue::Array(elements.into_iter().map(value_to_json).collect::<Vec<_>>())
+        }
+        Value::Map(elements) => JsonValue::Object(
+            elements
+                .into_iter()
+                .map(|(n, v)| (n, value_to_json(v)))
+                .collect(),
+        ),
+        Value::Struct(elements) => JsonValue::Object(
+            elements
+                .into_iter()
+                .map(|(n, v)| (n, value_to_json(v)))
+                .collect(),
+        ),
+    }
+}
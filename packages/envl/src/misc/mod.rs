@@ -1,3 +1,6 @@
+pub mod constraints;
+pub mod diagnostic;
 pub mod error;
 pub mod filesystem;
+pub mod json;
 pub mod vars;
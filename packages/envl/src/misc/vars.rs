@@ -7,6 +7,8 @@ use envl_vars::misc::variable::{Variable, VariableValue};
 pub struct Value {
     pub value: VariableValue,
     pub position: Position,
+    /// Where each string in `value` was written, keyed by its path inside `value`
+    pub string_positions: HashMap<Vec<String>, Position>,
 }
 
 pub type Variables = HashMap<String, Value>;
@@ -20,9 +22,43 @@ pub fn vars_to_hashmap(vars: Vec<Variable>) -> Variables {
             Value {
                 value: var.value,
                 position: var.position,
+                string_positions: var.string_positions,
             },
         );
     }
 
     hm
 }
+
+pub fn merge_value(base: VariableValue, overlay: VariableValue) -> VariableValue {
+    match (base, overlay) {
+        (VariableValue::Struct(mut base), VariableValue::Struct(overlay)) => {
+            for (name, value) in overlay {
+                let merged = match base.remove(&name) {
+                    Some(base_value) => merge_value(base_value, value),
+                    None => value,
+                };
+                base.insert(name, merged);
+            }
+            VariableValue::Struct(base)
+        }
+        (_, overlay) => overlay,
+    }
+}
+
+pub fn merge_vars(base: &mut Variables, overlay: Variables) {
+    for (name, value) in overlay {
+        let merged = match base.remove(&name) {
+            Some(mut base_value) => {
+                base_value.string_positions.extend(value.string_positions);
+                Value {
+                    value: merge_value(base_value.value, value.value),
+                    position: value.position,
+                    string_positions: base_value.string_positions,
+                }
+            }
+            None => value,
+        };
+        base.insert(name, merged);
+    }
+}
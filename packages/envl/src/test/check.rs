@@ -0,0 +1,200 @@
+#[cfg(test)]
+mod check_test {
+    use crate::{check_envl, load_envl_core, test::TestDir};
+
+    #[test]
+    fn check_reports_every_variable_test() {
+        let config = "settings {}\nvars {\n    a: int,\n    b: string,\n    c: Option<int>\n}";
+        let dir = TestDir::new(config, "a = \"123\";");
+        let config_path = dir.config_path();
+
+        let errors = check_envl(dir.path.to_owned(), config_path, config.to_string(), None);
+        let errors = errors
+            .iter()
+            .map(|err| {
+                (
+                    err.variable.to_owned(),
+                    err.position.to_owned().unwrap().row,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![(Some("a".to_string()), 1), (Some("b".to_string()), 4)]
+        );
+    }
+
+    #[test]
+    fn check_enum_variant_test() {
+        let config =
+            "settings {}\nvars {\n    a: enum { Debug, Info },\n    b: enum { On, Off }\n}";
+        let dir = TestDir::new(config, "a = \"Trace\";\nb = \"On\";");
+        let config_path = dir.config_path();
+
+        let errors = check_envl(dir.path.to_owned(), config_path, config.to_string(), None);
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].variable, Some("a".to_string()));
+        assert_eq!(errors[0].message, "Trace isn't a variant of the enum");
+    }
+
+    #[test]
+    fn check_constraints_test() {
+        let config = "settings {}\nvars {\n    a: uint ( min: 1, max: 65535 ),\n    b: string ( pattern: \"^https?://\" ),\n    c: string ( one_of: [\"debug\", \"info\"] ),\n    d: Array<int> ( max_len: 1 ),\n    e: int ( default: 0, min: 1 )\n}";
+        let dir = TestDir::new(
+            config,
+            "a = 70000;\nb = \"ftp://example.com\";\nc = \"info\";\nd = [1, 2];",
+        );
+        let config_path = dir.config_path();
+
+        let errors = check_envl(dir.path.to_owned(), config_path, config.to_string(), None);
+        let errors = errors
+            .iter()
+            .map(|err| {
+                (
+                    err.variable.to_owned().unwrap(),
+                    err.message.to_owned(),
+                    err.position.to_owned().unwrap().row,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "a".to_string(),
+                    "Value must be at most 65535".to_string(),
+                    1
+                ),
+                (
+                    "b".to_string(),
+                    "Value doesn't match the pattern ^https?://".to_string(),
+                    2
+                ),
+                ("d".to_string(), "Length must be at most 1".to_string(), 4),
+                ("e".to_string(), "Value must be at least 1".to_string(), 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_struct_missing_field_test() {
+        let config = "settings {}\nvars {\n    e: struct {\n        x: int;\n        y: int;\n        z: int;\n        w: Option<int>;\n    },\n    f: Array<struct {\n        a: string;\n    }>\n}";
+        let dir = TestDir::new(
+            config,
+            "e = struct { x: 1 };\nf = [struct { a: \"ok\" }, struct {}];",
+        );
+        let config_path = dir.config_path();
+
+        let errors = check_envl(dir.path.to_owned(), config_path, config.to_string(), None);
+        let errors = errors
+            .iter()
+            .map(|err| {
+                (
+                    err.variable.to_owned().unwrap(),
+                    err.message.to_owned(),
+                    err.position.to_owned().unwrap().row,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                ("e".to_string(), "Missing fields y, z".to_string(), 1),
+                ("f".to_string(), "Missing field a".to_string(), 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_large_integer_constraints_test() {
+        let config = "settings {}\nvars {\n    a: int ( max: 9007199254740992 ),\n    b: uint ( min: 18446744073709551615 ),\n    c: int ( min: -9007199254740992 )\n}";
+        let dir = TestDir::new(
+            config,
+            "a = 9007199254740993;\nb = 18446744073709551614;\nc = -9007199254740992;",
+        );
+        let config_path = dir.config_path();
+
+        let errors = check_envl(dir.path.to_owned(), config_path, config.to_string(), None);
+        let errors = errors
+            .iter()
+            .map(|err| (err.variable.to_owned().unwrap(), err.message.to_owned()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "a".to_string(),
+                    "Value must be at most 9007199254740992".to_string()
+                ),
+                (
+                    "b".to_string(),
+                    "Value must be at least 18446744073709551615".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn check_number_range_test() {
+        let config = "settings {}\nvars {\n    a: int,\n    b: uint,\n    c: int,\n    d: float,\n    e: float,\n    f: uint\n}";
+        let dir = TestDir::new(
+            config,
+            "a = 9_223_372_036_854_775_808;\nb = -1;\nc = 1.5;\nd = 1e400;\ne = 0x10;\nf = 0xFFFF_FFFF_FFFF_FFFF;",
+        );
+        let config_path = dir.config_path();
+
+        let errors = check_envl(dir.path.to_owned(), config_path, config.to_string(), None);
+        let errors = errors
+            .iter()
+            .map(|err| (err.variable.to_owned().unwrap(), err.message.to_owned()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                (
+                    "a".to_string(),
+                    "9_223_372_036_854_775_808 is out of range for int (-9223372036854775808 to 9223372036854775807)".to_string()
+                ),
+                (
+                    "b".to_string(),
+                    "-1 is out of range for uint (0 to 18446744073709551615)".to_string()
+                ),
+                ("c".to_string(), "1.5 isn't an integer".to_string()),
+                ("d".to_string(), "1e400 is out of range for float".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn load_reports_every_error_test() {
+        let config =
+            "settings {}\nvars {\n    a: int,\n    b: bool,\n    c: string,\n    d: Option<int>\n}";
+        let dir = TestDir::new(config, "a = \"1\";\nb = 1;\nc = \"ok\";\nd = true;");
+        let config_path = dir.config_path();
+
+        let errors = load_envl_core(dir.path.to_owned(), config_path, config.to_string(), None)
+            .unwrap_err()
+            .iter()
+            .map(|err| err.variable.to_owned().unwrap())
+            .collect::<Vec<_>>();
+
+        assert_eq!(errors, vec!["a", "b", "d"]);
+
+        let dir = TestDir::new(config, "a = ;\nb = true;\nc = [\"x\";\nd = 1;");
+        let config_path = dir.config_path();
+
+        let errors = load_envl_core(dir.path.to_owned(), config_path, config.to_string(), None)
+            .unwrap_err()
+            .iter()
+            .map(|err| err.position.to_owned().unwrap().row)
+            .collect::<Vec<_>>();
+
+        assert_eq!(errors, vec![1, 3]);
+    }
+}
@@ -0,0 +1,109 @@
+#[cfg(all(test, feature = "serde"))]
+mod de_test {
+    use std::collections::HashMap;
+
+    use envl_config::misc::variable::{Type, Value};
+    use serde::{Deserialize, Serialize};
+
+    use crate::{
+        de::{from_value, from_vars},
+        load_as,
+        test::{gen_var, TestDir},
+        VariableHashMap,
+    };
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Server {
+        host: String,
+        port: u16,
+    }
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Config {
+        server: Server,
+        tags: Vec<String>,
+        debug: Option<bool>,
+    }
+
+    #[test]
+    fn from_value_test() {
+        let value = Value::Struct(HashMap::from([
+            ("host".to_string(), Value::String("localhost".to_string())),
+            ("port".to_string(), Value::Uint(8080)),
+        ]));
+        let server = from_value::<Server>(value).unwrap();
+
+        assert_eq!(
+            server,
+            Server {
+                host: "localhost".to_string(),
+                port: 8080
+            }
+        );
+    }
+
+    #[test]
+    fn from_vars_error_test() {
+        let mut server = gen_var(
+            Type::Struct(HashMap::new()),
+            Value::Struct(HashMap::from([
+                ("host".to_string(), Value::String("localhost".to_string())),
+                ("port".to_string(), Value::String("8080".to_string())),
+            ])),
+        );
+        server.position.row = 2;
+        let mut data = VariableHashMap::new();
+        data.insert("server".to_string(), server);
+        data.insert(
+            "tags".to_string(),
+            gen_var(Type::Array(Box::from(Type::String)), Value::Array(vec![])),
+        );
+        let err = from_vars::<Config>(data).unwrap_err();
+
+        assert_eq!(err.variable, Some("server".to_string()));
+        assert_eq!(err.position.map(|p| p.row), Some(2));
+    }
+
+    #[test]
+    fn load_as_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    server: struct {\n        host: string;\n        port: uint;\n    },\n    tags: Array<string>,\n    debug: Option<bool>\n}",
+            "server = struct {\n    host: \"localhost\",\n    port: 8080\n};\ntags = [\"a\", \"b\"];",
+        );
+        let config_path = dir.config_path();
+
+        let config = load_as::<Config>(config_path).unwrap();
+
+        assert_eq!(
+            config,
+            Config {
+                server: Server {
+                    host: "localhost".to_string(),
+                    port: 8080
+                },
+                tags: vec!["a".to_string(), "b".to_string()],
+                debug: None
+            }
+        );
+    }
+
+    #[test]
+    fn to_string_round_trip_test() {
+        #[derive(Debug, Serialize, Deserialize, PartialEq)]
+        struct Paths {
+            a: String,
+            b: Vec<String>,
+        }
+
+        let paths = Paths {
+            a: "${HOME}/data".to_string(),
+            b: vec!["$${x}".to_string()],
+        };
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    a: string,\n    b: Array<string>\n}",
+            &envl_vars::to_string(&paths).unwrap(),
+        );
+
+        assert_eq!(load_as::<Paths>(dir.config_path()).unwrap(), paths);
+    }
+}
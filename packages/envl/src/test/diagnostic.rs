@@ -0,0 +1,49 @@
+#[cfg(test)]
+mod diagnostic_test {
+    use crate::{check_envl, misc::diagnostic::render, test::TestDir};
+
+    #[test]
+    fn render_test() {
+        let config = "settings {}\nvars {\n    a: int,\n    b: string\n}";
+        let dir = TestDir::new(config, "a = \"123\";");
+        let config_path = dir.config_path();
+        let envl_path = dir.file(".envl");
+
+        let errors = check_envl(
+            dir.path.to_owned(),
+            config_path.to_owned(),
+            config.to_string(),
+            None,
+        );
+        let rendered = errors.iter().map(render).collect::<Vec<_>>();
+
+        assert_eq!(
+            rendered,
+            vec![
+                [
+                    "error: Invalid type in a".to_string(),
+                    format!(" --> {}:1:1", envl_path),
+                    "  |".to_string(),
+                    "1 | a = \"123\";".to_string(),
+                    "  | ^ expected int".to_string(),
+                    "  |".to_string(),
+                    format!(" ::: {}:3:8", config_path),
+                    "  |".to_string(),
+                    "3 |     a: int,".to_string(),
+                    "  |        --- declared here".to_string(),
+                ]
+                .join("\n"),
+                [
+                    "error: Value is required in b".to_string(),
+                    format!(" --> {}:4:8", config_path),
+                    "  |".to_string(),
+                    "4 |     b: string".to_string(),
+                    "  |        ^^^^^^".to_string(),
+                    "  |".to_string(),
+                    "  = help: assign b in .envl or give it a default value".to_string(),
+                ]
+                .join("\n"),
+            ]
+        );
+    }
+}
@@ -0,0 +1,156 @@
+#[cfg(test)]
+mod env_test {
+    use std::env::{set_var, var};
+
+    use envl_config::misc::variable::Value;
+
+    use crate::{load, test::TestDir};
+
+    #[test]
+    fn load_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    a: int (\n        default: 123\n    ),\n    d: Array<int>,\n    e: struct {\n        v: struct {\n            a: string;\n        };\n    },\n    h: Option<bool>\n}",
+            "d = [1, 2];\ne = struct {\n    v: struct {\n        a: \"hello\"\n    }\n};",
+        );
+        let config_path = dir.config_path();
+
+        let env = load(config_path).unwrap();
+
+        assert_eq!(env.get_int("a"), Some(123));
+        assert_eq!(env.get_uint("a"), None);
+        assert_eq!(env.get_int("d.1"), Some(2));
+        assert_eq!(env.get_array("d").map(|a| a.len()), Some(2));
+        assert_eq!(env.get_str("e.v.a"), Some("hello"));
+        assert_eq!(env.get_str("e.v.b"), None);
+        assert_eq!(env.get("h"), Some(&Value::Null));
+    }
+
+    #[test]
+    fn load_actions_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    a: string ( actions: [ trim, lowercase ] ),\n    b: Array<string> ( actions: [ split(\",\"), trim, uppercase ] )\n}",
+            "a = \"  Info \";\nb = \"a, b ,c\";",
+        );
+        let config_path = dir.config_path();
+
+        let env = load(config_path).unwrap();
+
+        assert_eq!(env.get_str("a"), Some("info"));
+        assert_eq!(
+            env.get("b"),
+            Some(&Value::Array(vec![
+                Value::String("A".to_string()),
+                Value::String("B".to_string()),
+                Value::String("C".to_string())
+            ]))
+        );
+
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    c: string ( default: \"  ~/.cache \", actions: [ trim, expand_home ] ),\n    d: Array<string> ( default: [\" a \"], actions: [ split(\",\"), trim ] )\n}",
+            "",
+        );
+        let config_path = dir.config_path();
+
+        let env = load(config_path).unwrap();
+
+        assert_eq!(
+            env.get_str("c"),
+            Some(format!("{}/.cache", var("HOME").unwrap()).as_str())
+        );
+        assert_eq!(
+            env.get("d"),
+            Some(&Value::Array(vec![Value::String("a".to_string())]))
+        );
+
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    c: int ( actions: [ trim ] )\n}",
+            "c = 1;",
+        );
+        let config_path = dir.config_path();
+
+        let err = &load(config_path).unwrap_err()[0];
+
+        assert_eq!(err.variable, Some("c".to_string()));
+        assert_eq!(err.message, "trim can only be applied to strings");
+    }
+
+    #[test]
+    fn load_env_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    a: string ( env: \"ENVL_TEST_DATABASE_URL\" ),\n    b: Array<int> ( env: \"ENVL_TEST_PORTS\" ),\n    c: struct {\n        a: bool;\n    } ( env: \"ENVL_TEST_FLAGS\" ),\n    d: int ( env: \"ENVL_TEST_UNSET\" )\n}",
+            "a = \"postgres://localhost\";\nd = 1;",
+        );
+        let config_path = dir.config_path();
+        set_var("ENVL_TEST_DATABASE_URL", "postgres://production");
+        set_var("ENVL_TEST_PORTS", "[80, 443]");
+        set_var("ENVL_TEST_FLAGS", "{\"a\": true}");
+
+        let env = load(config_path.to_owned()).unwrap();
+
+        assert_eq!(env.get_str("a"), Some("postgres://production"));
+        assert_eq!(env.get_int("b.1"), Some(443));
+        assert_eq!(env.get_bool("c.a"), Some(true));
+        assert_eq!(env.get_int("d"), Some(1));
+
+        set_var("ENVL_TEST_PORTS", "80");
+
+        let err = &load(config_path).unwrap_err()[0];
+
+        assert_eq!(err.variable, Some("b".to_string()));
+        assert_eq!(
+            err.message,
+            "Environment variable ENVL_TEST_PORTS has an invalid value"
+        );
+    }
+
+    #[test]
+    fn load_env_null_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    a: struct {\n        a: Option<int>;\n    } ( env: \"ENVL_TEST_NULL_FIELD\" ),\n    b: Array<Option<int>> ( env: \"ENVL_TEST_NULL_ELEMENT\" )\n}",
+            "",
+        );
+        let config_path = dir.config_path();
+        set_var("ENVL_TEST_NULL_FIELD", "{\"a\": null}");
+        set_var("ENVL_TEST_NULL_ELEMENT", "[1, null]");
+
+        let env = load(config_path).unwrap();
+
+        assert_eq!(env.get("a.a"), Some(&Value::Null));
+        assert_eq!(
+            env.get("b"),
+            Some(&Value::Array(vec![Value::Int(1), Value::Null]))
+        );
+    }
+
+    #[test]
+    fn load_null_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    a: Option<int> ( default: 8080 ),\n    b: Option<int> ( default: 1 ),\n    c: Array<Option<string>>\n}",
+            "a = null;\nc = [\"x\", null];",
+        );
+        let config_path = dir.config_path();
+
+        let env = load(config_path).unwrap();
+
+        assert_eq!(env.get("a"), Some(&Value::Null));
+        assert_eq!(env.get_int("b"), Some(1));
+        assert_eq!(
+            env.get("c"),
+            Some(&Value::Array(vec![
+                Value::String("x".to_string()),
+                Value::Null
+            ]))
+        );
+
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    d: int ( default: 1 )\n}",
+            "d = null;",
+        );
+        let config_path = dir.config_path();
+
+        let err = &load(config_path).unwrap_err()[0];
+
+        assert_eq!(err.variable, Some("d".to_string()));
+        assert_eq!(err.message, "null can only be assigned to Option values");
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod export_test {
+    use std::collections::HashMap;
+
+    use envl_config::misc::variable::{Type, Value};
+
+    use crate::{
+        export::{export_vars, flatten_vars, ExportFormat, DEFAULT_SEPARATOR},
+        test::gen_var,
+        VarData, VariableHashMap,
+    };
+
+    fn gen_data() -> VariableHashMap {
+        HashMap::from([
+            (
+                "a".to_string(),
+                gen_var(Type::String, Value::String("it's $HOME".to_string())),
+            ),
+            (
+                "b".to_string(),
+                VarData {
+                    default_value: Value::Int(123),
+                    ..gen_var(Type::Int, Value::Null)
+                },
+            ),
+            (
+                "c".to_string(),
+                gen_var(Type::Option(Box::from(Type::Int)), Value::Null),
+            ),
+            (
+                "e".to_string(),
+                gen_var(
+                    Type::Struct(HashMap::from([(
+                        "v".to_string(),
+                        Type::Struct(HashMap::from([
+                            ("a".to_string(), Type::String),
+                            ("z".to_string(), Type::Array(Box::from(Type::String))),
+                        ])),
+                    )])),
+                    Value::Struct(HashMap::from([(
+                        "v".to_string(),
+                        Value::Struct(HashMap::from([
+                            ("a".to_string(), Value::String("hello".to_string())),
+                            (
+                                "z".to_string(),
+                                Value::Array(vec![
+                                    Value::String("x".to_string()),
+                                    Value::String("y".to_string()),
+                                ]),
+                            ),
+                        ])),
+                    )])),
+                ),
+            ),
+        ])
+    }
+
+    #[test]
+    fn flatten_test() {
+        let result = flatten_vars(gen_data(), "_");
+        assert_eq!(
+            result,
+            vec![
+                ("A".to_string(), "it's $HOME".to_string()),
+                ("B".to_string(), "123".to_string()),
+                ("E_V_A".to_string(), "hello".to_string()),
+                ("E_V_Z".to_string(), "[\"x\",\"y\"]".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn dotenv_test() {
+        let result = export_vars(gen_data(), ExportFormat::Dotenv, DEFAULT_SEPARATOR);
+        assert_eq!(
+            result,
+            "A=\"it's \\$HOME\"\nB=123\nE__V__A=hello\nE__V__Z=\"[\\\"x\\\",\\\"y\\\"]\""
+        );
+    }
+
+    #[test]
+    fn shell_test() {
+        let result = export_vars(gen_data(), ExportFormat::Shell, DEFAULT_SEPARATOR);
+        assert_eq!(
+            result,
+            "export A='it'\\''s $HOME'\nexport B=123\nexport E__V__A=hello\nexport E__V__Z='[\"x\",\"y\"]'"
+        );
+    }
+}
@@ -0,0 +1,163 @@
+#[cfg(test)]
+mod go_test {
+    use std::collections::{BTreeMap, HashMap};
+
+    use envl_config::misc::variable::{Type, Value};
+
+    use crate::{generator::go::generate_go_file, test::gen_var, VarData, VariableHashMap};
+
+    fn gen_go(name: &str, var: VarData) -> String {
+        let data: VariableHashMap = HashMap::from([(name.to_string(), var)]);
+        generate_go_file(data, "config/envl.go".to_string()).unwrap()
+    }
+
+    #[test]
+    fn primitive_test() {
+        let result = gen_go(
+            "db_host",
+            gen_var(Type::String, Value::String("localhost".to_string())),
+        );
+        assert_eq!(
+            result,
+            "package config
+
+type Env struct {
+\tDbHost string `json:\"db_host\"`
+}
+
+func Envl() Env {
+\treturn Env{
+\t\tDbHost: \"localhost\",
+\t}
+}
+
+func envlPtr[T any](v T) *T {
+\treturn &v
+}"
+        );
+    }
+
+    #[test]
+    fn option_and_array_test() {
+        let result = gen_go(
+            "a",
+            gen_var(
+                Type::Option(Box::from(Type::Array(Box::from(Type::Uint)))),
+                Value::Array(vec![Value::Uint(1), Value::Uint(2)]),
+            ),
+        );
+        assert!(result.contains("\tA *[]uint64 `json:\"a\"`\n"));
+        assert!(result.contains("\t\tA: envlPtr[[]uint64]([]uint64{1, 2}),\n"));
+    }
+
+    #[test]
+    fn struct_test() {
+        let result = gen_go(
+            "e",
+            gen_var(
+                Type::Struct(HashMap::from([(
+                    "w".to_string(),
+                    Type::Array(Box::from(Type::Struct(HashMap::from([(
+                        "a".to_string(),
+                        Type::Char,
+                    )])))),
+                )])),
+                Value::Struct(HashMap::from([(
+                    "w".to_string(),
+                    Value::Array(vec![Value::Struct(HashMap::from([(
+                        "a".to_string(),
+                        Value::Char('x'),
+                    )]))]),
+                )])),
+            ),
+        );
+        assert!(result.contains("type StructArrayStructew struct {\n\tA rune `json:\"a\"`\n}"));
+        assert!(result.contains("type Structe struct {\n\tW []StructArrayStructew `json:\"w\"`\n}"));
+        assert!(result
+            .contains("\t\tE: Structe{W: []StructArrayStructew{StructArrayStructew{A: 'x'}}},\n"));
+    }
+
+    #[test]
+    fn nested_type_name_test() {
+        let inner_type = Type::Struct(HashMap::from([(
+            "d".to_string(),
+            Type::Option(Box::from(Type::Struct(HashMap::from([(
+                "x".to_string(),
+                Type::Int,
+            )])))),
+        )]));
+        let inner_value = Value::Struct(HashMap::from([(
+            "d".to_string(),
+            Value::Struct(HashMap::from([("x".to_string(), Value::Int(1))])),
+        )]));
+        let result = gen_go(
+            "a",
+            gen_var(
+                Type::Struct(HashMap::from([
+                    ("b".to_string(), inner_type.to_owned()),
+                    ("c".to_string(), inner_type),
+                ])),
+                Value::Struct(HashMap::from([
+                    ("b".to_string(), inner_value.to_owned()),
+                    ("c".to_string(), inner_value),
+                ])),
+            ),
+        );
+        assert!(result
+            .contains("type StructStructab struct {\n\tD *StructStructStructabd `json:\"d\"`\n}"));
+        assert!(result
+            .contains("type StructStructac struct {\n\tD *StructStructStructacd `json:\"d\"`\n}"));
+        assert!(result.contains(
+            "C: StructStructac{D: envlPtr[StructStructStructacd](StructStructStructacd{X: 1})}"
+        ));
+    }
+
+    #[test]
+    fn map_test() {
+        let result = gen_go(
+            "a",
+            gen_var(
+                Type::Map(Box::from(Type::Int)),
+                Value::Map(BTreeMap::from([
+                    ("x".to_string(), Value::Int(1)),
+                    ("y".to_string(), Value::Int(2)),
+                ])),
+            ),
+        );
+        assert!(result.contains("\tA map[string]int64 `json:\"a\"`"));
+        assert!(result.contains("\t\tA: map[string]int64{\"x\": 1, \"y\": 2},"));
+    }
+
+    #[test]
+    fn sorted_output_test() {
+        let data: VariableHashMap = HashMap::from([
+            (
+                "c".to_string(),
+                gen_var(
+                    Type::Struct(HashMap::from([
+                        ("y".to_string(), Type::Int),
+                        ("x".to_string(), Type::Int),
+                    ])),
+                    Value::Struct(HashMap::from([
+                        ("y".to_string(), Value::Int(4)),
+                        ("x".to_string(), Value::Int(3)),
+                    ])),
+                ),
+            ),
+            ("b".to_string(), gen_var(Type::Int, Value::Int(2))),
+            ("a".to_string(), gen_var(Type::Int, Value::Int(1))),
+        ]);
+        let result = generate_go_file(data, "config/envl.go".to_string()).unwrap();
+        let positions = [
+            "\tA int64",
+            "\tB int64",
+            "\tC Structc",
+            "\t\tA: 1",
+            "\t\tB: 2",
+            "\t\tC: Structc{X: 3, Y: 4}",
+        ]
+        .map(|s| result.find(s).unwrap());
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+        assert!(result.find("\tX int64").unwrap() < result.find("\tY int64").unwrap());
+    }
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod interpolation_test {
+    use std::env::set_var;
+
+    use envl_config::misc::variable::Value;
+
+    use crate::{load, test::TestDir};
+
+    #[test]
+    fn interpolation_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    a: string,\n    b: string,\n    db: struct {\n        host: string;\n        port: int;\n        url: string;\n    },\n    c: string\n}",
+            "a = \"${db.url}/app\";\nb = \"${env:ENVL_TEST_DATA_DIR}/data $${raw}\";\ndb = struct {\n    host: \"localhost\",\n    port: 5432,\n    url: \"postgres://${db.host}:${db.port}\"\n};\nc = \"${b}\";",
+        );
+        let config_path = dir.config_path();
+        set_var("ENVL_TEST_DATA_DIR", "/var");
+
+        let env = load(config_path).unwrap();
+
+        assert_eq!(env.get_str("a"), Some("postgres://localhost:5432/app"));
+        assert_eq!(env.get_str("db.url"), Some("postgres://localhost:5432"));
+        assert_eq!(env.get_str("b"), Some("/var/data ${raw}"));
+        assert_eq!(env.get_str("c"), Some("/var/data ${raw}"));
+    }
+
+    #[test]
+    fn raw_string_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    a: string,\n    b: string\n}",
+            "a = r#\"{\"x\": \"${ENVL_UNDEFINED}\"}\"#;\nb = \"json: ${a}\";",
+        );
+        let config_path = dir.config_path();
+
+        let env = load(config_path).unwrap();
+
+        assert_eq!(env.get_str("a"), Some("{\"x\": \"${ENVL_UNDEFINED}\"}"));
+        assert_eq!(
+            env.get_str("b"),
+            Some("json: {\"x\": \"${ENVL_UNDEFINED}\"}")
+        );
+    }
+
+    #[test]
+    fn circular_reference_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    a: string,\n    b: string\n}",
+            "a = \"${b}\";\nb = \"${a}\";",
+        );
+        let config_path = dir.config_path();
+
+        let err = &load(config_path).unwrap_err()[0];
+
+        assert_eq!(err.message, "Circular reference a -> b -> a");
+        assert_eq!(err.variable, Some("b".to_string()));
+        let position = err.position.as_ref().unwrap();
+        assert_eq!((position.row, position.col), (2, 5));
+    }
+
+    #[test]
+    fn undefined_references_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    a: string,\n    b: string\n}",
+            "a = \"${x}\";\nb = \"${y}\";",
+        );
+        let config_path = dir.config_path();
+
+        let errors = load(config_path)
+            .unwrap_err()
+            .into_iter()
+            .map(|err| (err.message, err.variable))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            errors,
+            vec![
+                ("x isn't defined".to_string(), Some("a".to_string())),
+                ("y isn't defined".to_string(), Some("b".to_string())),
+            ]
+        );
+    }
+
+    #[test]
+    fn final_value_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    host: string ( env: \"ENVL_PROBE_HOST\" ),\n    port: int ( default: 5432 ),\n    user: string ( actions: [ trim ] ),\n    url: string\n}",
+            "host = \"localhost\";\nuser = \"  admin \";\nurl = \"${user}@${host}:${port}\";",
+        );
+        let config_path = dir.config_path();
+        set_var("ENVL_PROBE_HOST", "prod.db");
+
+        let env = load(config_path).unwrap();
+
+        assert_eq!(env.get_str("host"), Some("prod.db"));
+        assert_eq!(env.get_str("url"), Some("admin@prod.db:5432"));
+    }
+
+    #[test]
+    fn dotted_map_key_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    m: Map<string, string>,\n    a: string\n}",
+            r#"m = map { "x.y": "v", "z": "${m.\"x.y\"}!" };
+a = "${m.\"x.y\"}";"#,
+        );
+        let config_path = dir.config_path();
+
+        let env = load(config_path).unwrap();
+        let m = env.get_map("m").unwrap();
+
+        assert_eq!(m.get("x.y"), Some(&Value::String("v".to_string())));
+        assert_eq!(m.get("z"), Some(&Value::String("v!".to_string())));
+        assert_eq!(env.get_str("a"), Some("v"));
+    }
+
+    #[test]
+    fn error_position_test() {
+        let dir = TestDir::new(
+            "settings {}\nvars {\n    db: struct {\n        host: string;\n        url: string;\n    }\n}",
+            "db = struct {\n    host: \"localhost\",\n    url: \"${db.port}\"\n};",
+        );
+        let config_path = dir.config_path();
+
+        let err = &load(config_path).unwrap_err()[0];
+
+        assert_eq!(err.message, "db.port isn't defined");
+        let position = err.position.as_ref().unwrap();
+        assert_eq!((position.row, position.col), (3, 10));
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(test)]
+mod json_schema_test {
+    use envl_config::generate_ast;
+    use serde_json::{json, Value};
+
+    use crate::generator::json_schema::generate_json_schema;
+
+    fn gen_schema(code: &str) -> Value {
+        let config = generate_ast("test.envlconf".to_string(), code.to_string()).unwrap();
+        serde_json::from_str(&generate_json_schema(config).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn primitive_test() {
+        let schema = gen_schema(
+            "settings {} vars { a: string, b: uint, c: Option<int>, d: bool ( default: true ) }",
+        );
+        assert_eq!(
+            schema,
+            json!({
+                "$schema": "https://json-schema.org/draft/2020-12/schema",
+                "type": "object",
+                "properties": {
+                    "a": { "type": "string" },
+                    "b": { "type": "integer", "minimum": 0 },
+                    "c": { "type": ["integer", "null"] },
+                    "d": { "type": "boolean", "default": true }
+                },
+                "required": ["a", "b"],
+                "additionalProperties": false
+            })
+        );
+    }
+
+    #[test]
+    fn composite_test() {
+        let schema = gen_schema(
+            "settings {} vars { a: Array<struct { b: char; c: Option<float>; }>, d: Option<Array<int>> }",
+        );
+        assert_eq!(
+            schema["properties"],
+            json!({
+                "a": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "b": { "type": "string", "minLength": 1, "maxLength": 1 },
+                            "c": { "type": ["number", "null"] }
+                        },
+                        "required": ["b"],
+                        "additionalProperties": false
+                    }
+                },
+                "d": {
+                    "type": ["array", "null"],
+                    "items": { "type": "integer" }
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn enum_test() {
+        let schema = gen_schema(
+            "settings {} vars { a: enum { Debug, Info } ( default: \"Info\" ), b: Option<enum { On, Off }> }",
+        );
+        assert_eq!(
+            schema["properties"],
+            json!({
+                "a": { "type": "string", "enum": ["Debug", "Info"], "default": "Info" },
+                "b": { "type": ["string", "null"], "enum": ["On", "Off", null] }
+            })
+        );
+    }
+
+    #[test]
+    fn map_test() {
+        let schema = gen_schema("settings {} vars { a: Map<string, Array<int>> }");
+        assert_eq!(
+            schema["properties"],
+            json!({
+                "a": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "type": "array",
+                        "items": { "type": "integer" }
+                    }
+                }
+            })
+        );
+    }
+}
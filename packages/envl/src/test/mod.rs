@@ -0,0 +1,88 @@
+pub mod check;
+pub mod de;
+pub mod diagnostic;
+pub mod env;
+pub mod export;
+pub mod go;
+pub mod interpolation;
+pub mod json_schema;
+pub mod profile;
+pub mod python;
+pub mod typescript;
+
+#[cfg(test)]
+use std::{
+    env::temp_dir,
+    fs::{create_dir_all, remove_dir_all},
+    path::PathBuf,
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+#[cfg(test)]
+use envl_config::misc::variable::{Type, Value};
+#[cfg(test)]
+use envl_utils::types::Position;
+
+#[cfg(test)]
+use crate::{misc::filesystem::write_file, VarData};
+
+#[cfg(test)]
+pub fn gen_var(v_type: Type, value: Value) -> VarData {
+    VarData {
+        value,
+        v_type,
+        default_value: Value::Null,
+        basic_value: None,
+        position: Position {
+            file_path: "test.envl".to_string(),
+            row: 0,
+            col: 0,
+        },
+    }
+}
+
+/// A directory with a `.envlconf` and a `.envl` file that is removed once
+/// the test is done with it
+#[cfg(test)]
+pub struct TestDir {
+    pub path: PathBuf,
+}
+
+#[cfg(test)]
+impl TestDir {
+    pub fn new(config: &str, envl: &str) -> Self {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        let path = temp_dir().join(format!(
+            "envl_test_{}_{}",
+            process::id(),
+            COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
+        create_dir_all(&path).unwrap();
+
+        let dir = Self { path };
+        dir.write(".envlconf", config);
+        dir.write(".envl", envl);
+        dir
+    }
+
+    pub fn file(&self, name: &str) -> String {
+        self.path.join(name).display().to_string()
+    }
+
+    pub fn config_path(&self) -> String {
+        self.file(".envlconf")
+    }
+
+    pub fn write(&self, name: &str, content: &str) {
+        write_file(self.file(name), content.to_string()).unwrap();
+    }
+}
+
+#[cfg(test)]
+impl Drop for TestDir {
+    fn drop(&mut self) {
+        let _ = remove_dir_all(&self.path);
+    }
+}
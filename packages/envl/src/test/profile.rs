@@ -0,0 +1,88 @@
+#[cfg(test)]
+mod profile_test {
+    use envl_config::misc::variable::Value;
+
+    use crate::{load_envl_core, test::TestDir};
+
+    #[test]
+    fn profile_overlay_test() {
+        let config = "settings {}\nvars {\n    a: string,\n    b: struct {\n        x: int;\n        y: int;\n    },\n    c: bool\n}";
+        let dir = TestDir::new(
+            config,
+            "a = \"base\";\nb = struct {\n    x: 1,\n    y: 2\n};\nc = false;",
+        );
+        let config_path = dir.config_path();
+        dir.write(
+            ".envl.production",
+            "a = \"production\";\nb = struct {\n    y: 3\n};",
+        );
+        dir.write(".envl.local", "c = true;");
+
+        let result = load_envl_core(
+            dir.path.to_owned(),
+            config_path.to_owned(),
+            config.to_string(),
+            Some("production".to_string()),
+        )
+        .unwrap();
+
+        let a = result.get("a").unwrap();
+        assert_eq!(a.value, Value::String("production".to_string()));
+        assert!(a.position.file_path.ends_with(".envl.production"));
+        assert_eq!(a.position.row, 1);
+
+        let b = result.get("b").unwrap();
+        assert_eq!(
+            b.value,
+            Value::Struct(
+                [
+                    ("x".to_string(), Value::Int(1)),
+                    ("y".to_string(), Value::Int(3))
+                ]
+                .into_iter()
+                .collect()
+            )
+        );
+
+        let c = result.get("c").unwrap();
+        assert_eq!(c.value, Value::Bool(true));
+        assert!(c.position.file_path.ends_with(".envl.local"));
+
+        let result =
+            load_envl_core(dir.path.to_owned(), config_path, config.to_string(), None).unwrap();
+
+        assert_eq!(
+            result.get("a").unwrap().value,
+            Value::String("base".to_string())
+        );
+        assert!(result
+            .get("b")
+            .unwrap()
+            .position
+            .file_path
+            .ends_with(".envl"));
+    }
+
+    #[test]
+    fn missing_profile_overlay_test() {
+        let config = "settings {}\nvars {\n    a: string\n}";
+        let dir = TestDir::new(config, "a = \"base\";");
+        let config_path = dir.config_path();
+
+        let errors = load_envl_core(
+            dir.path.to_owned(),
+            config_path,
+            config.to_string(),
+            Some("prodution".to_string()),
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            errors[0].message,
+            format!(
+                "Profile prodution has no overlay file {}",
+                dir.file(".envl.prodution")
+            )
+        );
+    }
+}
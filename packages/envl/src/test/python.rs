@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod python_test {
+    use std::collections::HashMap;
+
+    use envl_config::misc::variable::{Type, Value};
+
+    use crate::{generator::python::generate_python_file, test::gen_var, VarData, VariableHashMap};
+
+    fn gen_python(name: &str, var: VarData) -> String {
+        let data: VariableHashMap = HashMap::from([(name.to_string(), var)]);
+        generate_python_file(data).unwrap()
+    }
+
+    #[test]
+    fn primitive_test() {
+        let result = gen_python(
+            "a",
+            gen_var(Type::String, Value::String("say \"hi\"\n".to_string())),
+        );
+        assert_eq!(
+            result,
+            "from dataclasses import dataclass
+from typing import Literal, Optional
+
+
+@dataclass(frozen=True)
+class Env:
+    a: str
+
+
+def envl() -> Env:
+    return Env(
+        a=\"say \\\"hi\\\"\\n\",
+    )"
+        );
+    }
+
+    #[test]
+    fn option_and_array_test() {
+        let result = gen_python(
+            "a",
+            gen_var(
+                Type::Option(Box::from(Type::Array(Box::from(Type::Bool)))),
+                Value::Array(vec![Value::Bool(true), Value::Bool(false)]),
+            ),
+        );
+        assert!(result.contains("    a: Optional[list[bool]]\n"));
+        assert!(result.contains("        a=[True, False],\n"));
+    }
+
+    #[test]
+    fn struct_test() {
+        let result = gen_python(
+            "e",
+            gen_var(
+                Type::Struct(HashMap::from([(
+                    "v".to_string(),
+                    Type::Struct(HashMap::from([("a".to_string(), Type::Int)])),
+                )])),
+                Value::Struct(HashMap::from([(
+                    "v".to_string(),
+                    Value::Struct(HashMap::from([("a".to_string(), Value::Int(-1))])),
+                )])),
+            ),
+        );
+        assert_eq!(
+            result,
+            "from dataclasses import dataclass
+from typing import Literal, Optional
+
+
+@dataclass(frozen=True)
+class StructStructev:
+    a: int
+
+
+@dataclass(frozen=True)
+class Structe:
+    v: StructStructev
+
+
+@dataclass(frozen=True)
+class Env:
+    e: Structe
+
+
+def envl() -> Env:
+    return Env(
+        e=Structe(v=StructStructev(a=-1)),
+    )"
+        );
+    }
+
+    #[test]
+    fn enum_test() {
+        let result = gen_python(
+            "a",
+            gen_var(
+                Type::Enum(vec!["Debug".to_string(), "Info".to_string()]),
+                Value::String("Info".to_string()),
+            ),
+        );
+        assert_eq!(
+            result,
+            "from dataclasses import dataclass
+from typing import Literal, Optional
+
+
+@dataclass(frozen=True)
+class Env:
+    a: Literal[\"Debug\", \"Info\"]
+
+
+def envl() -> Env:
+    return Env(
+        a=\"Info\",
+    )"
+        );
+    }
+
+    #[test]
+    fn sorted_output_test() {
+        let data: VariableHashMap = HashMap::from([
+            (
+                "c".to_string(),
+                gen_var(
+                    Type::Struct(HashMap::from([
+                        ("y".to_string(), Type::Int),
+                        ("x".to_string(), Type::Int),
+                    ])),
+                    Value::Struct(HashMap::from([
+                        ("y".to_string(), Value::Int(4)),
+                        ("x".to_string(), Value::Int(3)),
+                    ])),
+                ),
+            ),
+            ("b".to_string(), gen_var(Type::Int, Value::Int(2))),
+            ("a".to_string(), gen_var(Type::Int, Value::Int(1))),
+        ]);
+        let result = generate_python_file(data).unwrap();
+        let positions = [
+            "a: int",
+            "b: int",
+            "c: Structc",
+            "a=1",
+            "b=2",
+            "c=Structc(x=3, y=4)",
+        ]
+        .map(|s| result.find(s).unwrap());
+        assert!(positions.windows(2).all(|w| w[0] < w[1]));
+        assert!(result.find("x: int").unwrap() < result.find("y: int").unwrap());
+    }
+}
@@ -0,0 +1,129 @@
+#[cfg(test)]
+mod typescript_test {
+    use std::collections::{BTreeMap, HashMap};
+
+    use envl_config::misc::variable::{Type, Value};
+
+    use crate::{generator::typescript::generate_ts_file, test::gen_var, VarData, VariableHashMap};
+
+    fn gen_ts(name: &str, var: VarData) -> String {
+        let data: VariableHashMap = HashMap::from([(name.to_string(), var)]);
+        generate_ts_file(data).unwrap()
+    }
+
+    #[test]
+    fn primitive_test() {
+        let result = gen_ts("a", gen_var(Type::String, Value::String("123".to_string())));
+        assert_eq!(
+            result,
+            "export interface Env { a : string ; } export function envl () : Env { return { a : \"123\" , } ; }"
+        );
+    }
+
+    #[test]
+    fn option_test() {
+        let result = gen_ts(
+            "a",
+            gen_var(Type::Option(Box::from(Type::Int)), Value::Null),
+        );
+        assert_eq!(
+            result,
+            "export interface Env { a : number | null ; } export function envl () : Env { return { a : null , } ; }"
+        );
+    }
+
+    #[test]
+    fn array_test() {
+        let result = gen_ts(
+            "a",
+            gen_var(
+                Type::Array(Box::from(Type::Option(Box::from(Type::Bool)))),
+                Value::Array(vec![Value::Bool(true), Value::Null]),
+            ),
+        );
+        assert_eq!(
+            result,
+            "export interface Env { a : (boolean | null) [] ; } export function envl () : Env { return { a : [true , null ,] , } ; }"
+        );
+    }
+
+    #[test]
+    fn struct_test() {
+        let result = gen_ts(
+            "e",
+            gen_var(
+                Type::Struct(HashMap::from([(
+                    "v".to_string(),
+                    Type::Struct(HashMap::from([("a".to_string(), Type::Char)])),
+                )])),
+                Value::Struct(HashMap::from([(
+                    "v".to_string(),
+                    Value::Struct(HashMap::from([("a".to_string(), Value::Char('x'))])),
+                )])),
+            ),
+        );
+        assert_eq!(
+            result,
+            "export interface StructStructev { a : string ; } export interface Structe { v : StructStructev ; } export interface Env { e : Structe ; } export function envl () : Env { return { e : { v : { a : \"x\" , } , } , } ; }"
+        );
+    }
+
+    #[test]
+    fn enum_test() {
+        let result = gen_ts(
+            "a",
+            gen_var(
+                Type::Array(Box::from(Type::Enum(vec![
+                    "Debug".to_string(),
+                    "Info".to_string(),
+                ]))),
+                Value::Array(vec![Value::String("Info".to_string())]),
+            ),
+        );
+        assert_eq!(
+            result,
+            "export interface Env { a : (\"Debug\" | \"Info\") [] ; } export function envl () : Env { return { a : [\"Info\" ,] , } ; }"
+        );
+    }
+
+    #[test]
+    fn map_test() {
+        let result = gen_ts(
+            "a",
+            gen_var(
+                Type::Map(Box::from(Type::Bool)),
+                Value::Map(BTreeMap::from([("x-y".to_string(), Value::Bool(true))])),
+            ),
+        );
+        assert_eq!(
+            result,
+            "export interface Env { a : Record < string , boolean > ; } export function envl () : Env { return { a : { \"x-y\" : true , } , } ; }"
+        );
+    }
+
+    #[test]
+    fn sorted_output_test() {
+        let data: VariableHashMap = HashMap::from([
+            (
+                "c".to_string(),
+                gen_var(
+                    Type::Struct(HashMap::from([
+                        ("y".to_string(), Type::Int),
+                        ("x".to_string(), Type::Int),
+                    ])),
+                    Value::Struct(HashMap::from([
+                        ("y".to_string(), Value::Int(4)),
+                        ("x".to_string(), Value::Int(3)),
+                    ])),
+                ),
+            ),
+            ("b".to_string(), gen_var(Type::Int, Value::Int(2))),
+            ("a".to_string(), gen_var(Type::Int, Value::Int(1))),
+        ]);
+        let result = generate_ts_file(data).unwrap();
+        assert_eq!(
+            result,
+            "export interface Structc { x : number ; y : number ; } export interface Env { a : number ; b : number ; c : Structc ; } export function envl () : Env { return { a : 1 , b : 2 , c : { x : 3 , y : 4 , } , } ; }"
+        );
+    }
+}
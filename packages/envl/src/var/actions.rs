@@ -0,0 +1,105 @@
+use std::env::var;
+
+use envl_config::misc::{config::Action, variable::Value};
+use envl_vars::misc::variable::VariableValue;
+
+use crate::misc::error::{convert_envl_lib_error, EnvlError, EnvlLibError};
+
+fn action_name(action: &Action) -> &'static str {
+    match action {
+        Action::Trim => "trim",
+        Action::Lowercase => "lowercase",
+        Action::Uppercase => "uppercase",
+        Action::ExpandHome => "expand_home",
+        Action::Split(_) => "split",
+    }
+}
+
+fn expand_home(value: String) -> String {
+    match (value.strip_prefix('~'), var("HOME")) {
+        (Some(rest), Ok(home)) if rest.is_empty() || rest.starts_with('/') => {
+            format!("{}{}", home, rest)
+        }
+        _ => value,
+    }
+}
+
+fn apply_string_action(value: String, action: &Action) -> String {
+    match action {
+        Action::Trim => value.trim().to_string(),
+        Action::Lowercase => value.to_lowercase(),
+        Action::Uppercase => value.to_uppercase(),
+        Action::ExpandHome => expand_home(value),
+        Action::Split(_) => value,
+    }
+}
+
+fn invalid_target(action: &Action) -> Box<EnvlError> {
+    Box::from(convert_envl_lib_error(EnvlLibError {
+        message: format!("{} can only be applied to strings", action_name(action)),
+    }))
+}
+
+fn apply_action(value: VariableValue, action: &Action) -> Result<VariableValue, Box<EnvlError>> {
+    match value {
+        VariableValue::String(s) | VariableValue::RawString(s) => Ok(match action {
+            Action::Split(separator) => VariableValue::Array(
+                s.split(separator.as_str())
+                    .map(|element| VariableValue::String(element.to_string()))
+                    .collect(),
+            ),
+            action => VariableValue::String(apply_string_action(s, action)),
+        }),
+        VariableValue::Array(elements) if !matches!(action, Action::Split(_)) => {
+            let mut result = Vec::new();
+            for element in elements {
+                result.push(apply_action(element, action)?);
+            }
+            Ok(VariableValue::Array(result))
+        }
+        VariableValue::Null => Ok(VariableValue::Null),
+        _ => Err(invalid_target(action)),
+    }
+}
+
+fn apply_default_action(value: Value, action: &Action) -> Result<Value, Box<EnvlError>> {
+    match value {
+        Value::String(s) => Ok(Value::String(apply_string_action(s, action))),
+        Value::Array(elements) => {
+            let mut result = Vec::new();
+            for element in elements {
+                result.push(apply_default_action(element, action)?);
+            }
+            Ok(Value::Array(result))
+        }
+        Value::Null => Ok(Value::Null),
+        _ => Err(invalid_target(action)),
+    }
+}
+
+pub fn apply_actions(
+    value: VariableValue,
+    actions: &[Action],
+) -> Result<VariableValue, Box<EnvlError>> {
+    let mut value = value;
+
+    for action in actions {
+        value = apply_action(value, action)?;
+    }
+
+    Ok(value)
+}
+
+/// Defaults are written in the final type, so `split` has already happened
+/// and only the other actions are applied
+pub fn apply_default_actions(value: Value, actions: &[Action]) -> Result<Value, Box<EnvlError>> {
+    let mut value = value;
+
+    for action in actions {
+        if !matches!(action, Action::Split(_)) {
+            value = apply_default_action(value, action)?;
+        }
+    }
+
+    Ok(value)
+}
@@ -0,0 +1,96 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    env::var,
+};
+
+use envl_config::misc::variable::Type;
+use envl_vars::misc::{num::Number, variable::VariableValue};
+use serde_json::Value as JsonValue;
+
+use crate::misc::error::{convert_envl_lib_error, EnvlError, EnvlLibError};
+
+fn json_to_variable_value(value: JsonValue, t: &Type) -> Option<VariableValue> {
+    match (value, t) {
+        (JsonValue::Null, Type::Option(_)) => Some(VariableValue::Null),
+        (v, Type::Option(t) | Type::Named(_, t)) => json_to_variable_value(v, t),
+        (JsonValue::String(s), Type::Char) => {
+            let mut chars = s.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(VariableValue::Char(c)),
+                _ => None,
+            }
+        }
+        (JsonValue::String(s), Type::String | Type::Enum(_)) => Some(VariableValue::String(s)),
+        (JsonValue::Number(n), Type::Int | Type::Uint | Type::Float) => {
+            Number::parse(&n.to_string()).map(VariableValue::Number)
+        }
+        (JsonValue::Bool(b), Type::Bool) => Some(VariableValue::Bool(b)),
+        (JsonValue::Array(elements), Type::Array(t)) => {
+            let mut result = Vec::new();
+            for element in elements {
+                result.push(json_to_variable_value(element, t)?);
+            }
+            Some(VariableValue::Array(result))
+        }
+        (JsonValue::Object(elements), Type::Struct(types)) => {
+            let mut result = HashMap::new();
+            for (name, element) in elements {
+                let t = types.get(&name)?;
+                result.insert(name, json_to_variable_value(element, t)?);
+            }
+            Some(VariableValue::Struct(result))
+        }
+        (JsonValue::Object(elements), Type::Map(t)) => {
+            let mut result = BTreeMap::new();
+            for (name, element) in elements {
+                result.insert(name, json_to_variable_value(element, t)?);
+            }
+            Some(VariableValue::Map(result))
+        }
+        _ => None,
+    }
+}
+
+pub fn parse_env_value(value: String, t: &Type) -> Option<VariableValue> {
+    match t {
+        Type::Option(t) | Type::Named(_, t) => parse_env_value(value, t),
+        Type::String | Type::Enum(_) => Some(VariableValue::String(value)),
+        Type::Char => {
+            let mut chars = value.chars();
+            match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(VariableValue::Char(c)),
+                _ => None,
+            }
+        }
+        Type::Int | Type::Uint | Type::Float => {
+            Number::parse(value.trim()).map(VariableValue::Number)
+        }
+        Type::Bool => match value.trim() {
+            "true" => Some(VariableValue::Bool(true)),
+            "false" => Some(VariableValue::Bool(false)),
+            _ => None,
+        },
+        Type::Array(_) | Type::Struct(_) | Type::Map(_) => {
+            match serde_json::from_str::<JsonValue>(&value) {
+                Ok(json) => json_to_variable_value(json, t),
+                Err(_) => None,
+            }
+        }
+        Type::Null => None,
+    }
+}
+
+pub fn read_env_var(name: &str, t: &Type) -> Result<Option<VariableValue>, Box<EnvlError>> {
+    match var(name) {
+        Ok(value) => match parse_env_value(value, t) {
+            Some(v) => Ok(Some(v)),
+            None => Err(Box::from(
+                convert_envl_lib_error(EnvlLibError {
+                    message: format!("Environment variable {} has an invalid value", name),
+                })
+                .with_help(format!("set {} to a value of type {}", name, t)),
+            )),
+        },
+        Err(_) => Ok(None),
+    }
+}
@@ -0,0 +1,330 @@
+use std::{collections::HashSet, env::var};
+
+use envl_config::misc::variable::Value;
+use envl_vars::misc::variable::VariableValue;
+
+use crate::misc::{
+    error::{convert_envl_lib_error, convert_envl_var_error, EnvlError, EnvlLibError},
+    vars::Value as VarsValue,
+};
+
+/// Gives the text of a reference to another variable. `Err(None)` means the
+/// variable failed to load and its error is already reported
+pub type Resolve<'a> = dyn FnMut(&[String]) -> Result<String, Option<String>> + 'a;
+
+enum Segment {
+    Text(String),
+    Reference(String),
+}
+
+fn parse_segments(value: &str) -> Result<Vec<Segment>, String> {
+    let mut segments = Vec::new();
+    let mut text = String::new();
+    let mut rest = value;
+
+    while let Some(index) = rest.find('$') {
+        text.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        if rest.starts_with("$${") {
+            text.push_str("${");
+            rest = &rest[3..];
+        } else if let Some(reference) = rest.strip_prefix("${") {
+            match reference.find('}') {
+                Some(end) => {
+                    if !text.is_empty() {
+                        segments.push(Segment::Text(text.clone()));
+                        text.clear();
+                    }
+                    segments.push(Segment::Reference(reference[..end].trim().to_string()));
+                    rest = &reference[end + 1..];
+                }
+                None => {
+                    return Err(format!("Interpolation in {} isn't closed", value));
+                }
+            }
+        } else {
+            text.push('$');
+            rest = &rest[1..];
+        }
+    }
+    text.push_str(rest);
+    if !text.is_empty() {
+        segments.push(Segment::Text(text));
+    }
+
+    Ok(segments)
+}
+
+/// Splits `db.hosts.0` into its segments. A segment with a `.` in it is
+/// quoted, as in `m."x.y"`
+fn parse_reference(reference: &str) -> Result<Vec<String>, String> {
+    let invalid = || format!("{} isn't a valid reference", reference);
+    let mut segments = Vec::new();
+    let mut rest = reference;
+
+    loop {
+        let (segment, after) = match rest.strip_prefix('"') {
+            Some(quoted) => {
+                let end = quoted.find('"').ok_or_else(invalid)?;
+                (&quoted[..end], &quoted[end + 1..])
+            }
+            None => {
+                let end = rest.find('.').unwrap_or(rest.len());
+                (&rest[..end], &rest[end..])
+            }
+        };
+        segments.push(segment.to_string());
+
+        match after.strip_prefix('.') {
+            Some(after) => rest = after,
+            None if after.is_empty() => break,
+            None => return Err(invalid()),
+        }
+    }
+
+    if segments[0].is_empty() {
+        return Err(invalid());
+    }
+
+    Ok(segments)
+}
+
+/// Writes a path the way it is referenced, quoting segments that contain a `.`
+pub fn display_path(path: &[String]) -> String {
+    path.iter()
+        .map(|segment| {
+            if segment.is_empty() || segment.contains('.') {
+                format!("\"{}\"", segment)
+            } else {
+                segment.to_owned()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+/// Finds the element at `path` inside a loaded value
+pub fn lookup_value<'a>(value: &'a Value, path: &[String]) -> Option<&'a Value> {
+    let mut current = value;
+
+    for segment in path {
+        current = match current {
+            Value::Struct(elements) => elements.get(segment)?,
+            Value::Map(elements) => elements.get(segment)?,
+            Value::Array(elements) => elements.get(segment.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+
+    Some(current)
+}
+
+/// The text a loaded value is interpolated as
+pub fn value_text(path: &[String], value: &Value) -> Result<String, String> {
+    match value {
+        Value::String(s) => Ok(s.to_owned()),
+        Value::Char(c) => Ok(c.to_string()),
+        Value::Int(n) => Ok(n.to_string()),
+        Value::Uint(n) => Ok(n.to_string()),
+        Value::Float(n) => Ok(n.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        Value::Null => Err(format!("{} has no value", display_path(path))),
+        _ => Err(format!("{} can't be interpolated", display_path(path))),
+    }
+}
+
+fn lookup<'a>(value: &'a VariableValue, path: &[String]) -> Option<&'a VariableValue> {
+    let mut current = value;
+
+    for segment in path {
+        current = match current {
+            VariableValue::Struct(elements) => elements.get(segment)?,
+            VariableValue::Map(elements) => elements.get(segment)?,
+            VariableValue::Array(elements) => elements.get(segment.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+
+    Some(current)
+}
+
+fn lookup_mut<'a>(value: &'a mut VariableValue, path: &[String]) -> Option<&'a mut VariableValue> {
+    let mut current = value;
+
+    for segment in path {
+        current = match current {
+            VariableValue::Struct(elements) => elements.get_mut(segment)?,
+            VariableValue::Map(elements) => elements.get_mut(segment)?,
+            VariableValue::Array(elements) => elements.get_mut(segment.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+
+    Some(current)
+}
+
+fn string_paths(path: Vec<String>, value: &VariableValue, paths: &mut Vec<Vec<String>>) {
+    let element_path = |segment: &str| {
+        let mut path = path.clone();
+        path.push(segment.to_string());
+        path
+    };
+
+    match value {
+        VariableValue::String(_) => paths.push(path),
+        VariableValue::Array(elements) => {
+            for (i, element) in elements.iter().enumerate() {
+                string_paths(element_path(&i.to_string()), element, paths);
+            }
+        }
+        VariableValue::Struct(elements) => {
+            let mut names = elements.keys().collect::<Vec<_>>();
+            names.sort();
+            for name in names {
+                string_paths(element_path(name), &elements[name], paths);
+            }
+        }
+        VariableValue::Map(elements) => {
+            for (name, element) in elements {
+                string_paths(element_path(name), element, paths);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Interpolates the strings of one variable. References into the variable
+/// itself read its own strings, everything else goes through `resolve`
+struct Interpolation<'a, 'b> {
+    name: &'a str,
+    value: VariableValue,
+    resolved: HashSet<Vec<String>>,
+    resolve: &'a mut Resolve<'b>,
+}
+
+impl Interpolation<'_, '_> {
+    fn resolve_path(
+        &mut self,
+        path: &[String],
+        stack: &mut Vec<Vec<String>>,
+    ) -> Result<String, Option<String>> {
+        if path[0] != self.name {
+            return (self.resolve)(path);
+        }
+
+        let value = match lookup(&self.value, &path[1..]) {
+            Some(value) => value.clone(),
+            None => {
+                return Err(Some(format!("{} isn't defined", display_path(path))));
+            }
+        };
+        let value = match value {
+            VariableValue::String(s) => s,
+            VariableValue::RawString(s) => {
+                return Ok(s);
+            }
+            VariableValue::Number(n) => {
+                return Ok(n.to_string());
+            }
+            VariableValue::Bool(b) => {
+                return Ok(b.to_string());
+            }
+            VariableValue::Char(c) => {
+                return Ok(c.to_string());
+            }
+            _ => {
+                return Err(Some(format!(
+                    "{} can't be interpolated",
+                    display_path(path)
+                )));
+            }
+        };
+        if self.resolved.contains(path) {
+            return Ok(value);
+        }
+        if stack.iter().any(|p| p == path) {
+            stack.push(path.to_vec());
+            let cycle = stack
+                .iter()
+                .map(|p| display_path(p))
+                .collect::<Vec<_>>()
+                .join(" -> ");
+            return Err(Some(format!("Circular reference {}", cycle)));
+        }
+
+        stack.push(path.to_vec());
+        let mut result = String::new();
+        for segment in parse_segments(&value)? {
+            match segment {
+                Segment::Text(text) => result.push_str(&text),
+                Segment::Reference(reference) => match reference.strip_prefix("env:") {
+                    Some(name) => match var(name) {
+                        Ok(v) => result.push_str(&v),
+                        Err(_) => {
+                            return Err(Some(format!("Environment variable {} isn't set", name)));
+                        }
+                    },
+                    None => {
+                        let reference = parse_reference(&reference)?;
+                        result.push_str(&self.resolve_path(&reference, stack)?);
+                    }
+                },
+            }
+        }
+        stack.pop();
+
+        if let Some(target) = lookup_mut(&mut self.value, &path[1..]) {
+            *target = VariableValue::String(result.clone());
+        }
+        self.resolved.insert(path.to_vec());
+
+        Ok(result)
+    }
+}
+
+/// Resolves the `${path}` and `${env:NAME}` references in the strings of a
+/// `.envl` value. Errors point at the string they were found in
+pub fn resolve_interpolations(
+    name: &str,
+    value: &VarsValue,
+    resolve: &mut Resolve<'_>,
+) -> Result<VariableValue, Vec<EnvlError>> {
+    let mut paths = Vec::new();
+    string_paths(vec![name.to_string()], &value.value, &mut paths);
+
+    let mut interpolation = Interpolation {
+        name,
+        value: value.value.clone(),
+        resolved: HashSet::new(),
+        resolve,
+    };
+    let mut errors = Vec::new();
+
+    for path in paths {
+        match interpolation.resolve_path(&path, &mut Vec::new()) {
+            Ok(_) => {}
+            Err(Some(message)) => {
+                let position = value
+                    .string_positions
+                    .get(&path[1..])
+                    .unwrap_or(&value.position);
+                errors.push(convert_envl_var_error(
+                    convert_envl_lib_error(EnvlLibError { message }),
+                    name.to_owned(),
+                    position.clone(),
+                ));
+            }
+            // The referenced variable reports its own error
+            Err(None) => {
+                return Err(errors);
+            }
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(interpolation.value)
+    } else {
+        Err(errors)
+    }
+}
@@ -1,17 +1,21 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use envl_config::misc::variable::{Type, Value};
 use envl_vars::misc::variable::VariableValue;
 
 use crate::misc::error::{convert_envl_lib_error, EnvlError, EnvlLibError};
 
+pub mod actions;
+pub mod environment;
+pub mod interpolation;
+
 pub fn parse_var(t: Type, v: VariableValue) -> Result<Value, Box<EnvlError>> {
     match &t {
         Type::Null => {
             return Ok(Value::Null);
         }
         Type::String => {
-            if let VariableValue::String(value) = &v {
+            if let VariableValue::String(value) | VariableValue::RawString(value) = &v {
                 return Ok(Value::String(value.clone()));
             }
         }
@@ -22,23 +26,45 @@ pub fn parse_var(t: Type, v: VariableValue) -> Result<Value, Box<EnvlError>> {
         }
         Type::Float => {
             if let VariableValue::Number(n) = &v {
-                if let Ok(f) = n.parse::<f64>() {
-                    return Ok(Value::Float(f));
-                }
+                return match n.to_f64() {
+                    Ok(f) => Ok(Value::Float(f)),
+                    Err(message) => {
+                        Err(Box::from(convert_envl_lib_error(EnvlLibError { message })))
+                    }
+                };
             }
         }
         Type::Int => {
             if let VariableValue::Number(n) = &v {
-                if let Ok(i) = n.parse::<i64>() {
-                    return Ok(Value::Int(i));
-                }
+                return match n.to_i64() {
+                    Ok(i) => Ok(Value::Int(i)),
+                    Err(message) => {
+                        Err(Box::from(convert_envl_lib_error(EnvlLibError { message })))
+                    }
+                };
             }
         }
         Type::Uint => {
             if let VariableValue::Number(n) = &v {
-                if let Ok(u) = n.parse::<u64>() {
-                    return Ok(Value::Uint(u));
+                return match n.to_u64() {
+                    Ok(u) => Ok(Value::Uint(u)),
+                    Err(message) => {
+                        Err(Box::from(convert_envl_lib_error(EnvlLibError { message })))
+                    }
+                };
+            }
+        }
+        Type::Enum(variants) => {
+            if let VariableValue::String(value) | VariableValue::RawString(value) = &v {
+                if variants.contains(value) {
+                    return Ok(Value::String(value.clone()));
                 }
+                return Err(Box::from(
+                    convert_envl_lib_error(EnvlLibError {
+                        message: format!("{} isn't a variant of the enum", value),
+                    })
+                    .with_help(format!("expected one of {}", variants.join(", "))),
+                ));
             }
         }
         Type::Bool => {
@@ -46,6 +72,12 @@ pub fn parse_var(t: Type, v: VariableValue) -> Result<Value, Box<EnvlError>> {
                 return Ok(Value::Bool(b.to_owned()));
             }
         }
+        Type::Named(_, t) => {
+            return parse_var(*t.to_owned(), v);
+        }
+        Type::Option(_) if v == VariableValue::Null => {
+            return Ok(Value::Null);
+        }
         Type::Option(t) => {
             return match parse_var(*t.to_owned(), v) {
                 Ok(value) => Ok(value),
@@ -71,6 +103,25 @@ pub fn parse_var(t: Type, v: VariableValue) -> Result<Value, Box<EnvlError>> {
                 return Ok(Value::Array(results));
             }
         }
+        Type::Map(boxed_type) => {
+            if let VariableValue::Map(elements) = &v {
+                let element_type = *boxed_type.clone();
+                let mut results = BTreeMap::new();
+
+                for (key, element) in elements {
+                    match parse_var(element_type.clone(), element.clone()) {
+                        Ok(e) => {
+                            results.insert(key.clone(), e);
+                        }
+                        Err(err) => {
+                            return Err(err);
+                        }
+                    }
+                }
+
+                return Ok(Value::Map(results));
+            }
+        }
         Type::Struct(elements) => {
             if let VariableValue::Struct(vars) = &v {
                 let mut hm = HashMap::new();
@@ -86,19 +137,62 @@ pub fn parse_var(t: Type, v: VariableValue) -> Result<Value, Box<EnvlError>> {
                             }
                         }
                     } else {
-                        dbg!(t.to_owned(), v.to_owned());
-                        return Err(Box::from(convert_envl_lib_error(EnvlLibError {
-                            message: "Invalid type".to_string(),
-                        })));
+                        return Err(Box::from(
+                            convert_envl_lib_error(EnvlLibError {
+                                message: "Invalid type".to_string(),
+                            })
+                            .with_label(format!("{} isn't a field of {}", name, t)),
+                        ));
                     }
                 }
 
+                let mut missing = elements
+                    .iter()
+                    .filter(|(name, t)| !vars.contains_key(*name) && !is_optional(t))
+                    .map(|(name, _)| name.to_owned())
+                    .collect::<Vec<_>>();
+                if !missing.is_empty() {
+                    missing.sort();
+                    return Err(Box::from(
+                        convert_envl_lib_error(EnvlLibError {
+                            message: match missing.len() {
+                                1 => format!("Missing field {}", missing[0]),
+                                _ => format!("Missing fields {}", missing.join(", ")),
+                            },
+                        })
+                        .with_help(format!(
+                            "every field of {} except Option fields is required",
+                            t
+                        )),
+                    ));
+                }
+
                 return Ok(Value::Struct(hm));
             }
         }
     }
 
-    Err(Box::from(convert_envl_lib_error(EnvlLibError {
-        message: "Invalid type".to_string(),
-    })))
+    if v == VariableValue::Null {
+        return Err(Box::from(
+            convert_envl_lib_error(EnvlLibError {
+                message: "null can only be assigned to Option values".to_string(),
+            })
+            .with_help(format!("declare it as Option<{}> to allow null", t)),
+        ));
+    }
+
+    Err(Box::from(
+        convert_envl_lib_error(EnvlLibError {
+            message: "Invalid type".to_string(),
+        })
+        .with_label(format!("expected {}", t)),
+    ))
+}
+
+fn is_optional(t: &Type) -> bool {
+    match t {
+        Type::Option(_) => true,
+        Type::Named(_, t) => is_optional(t),
+        _ => false,
+    }
 }
@@ -1,7 +1,8 @@
-use envl::load_envl;
+use envl::{load_envl, misc::diagnostic::render};
 
 fn main() {
-    if let Err(err) = load_envl("src/envl.rs".to_string()) {
-        panic!("{:?}", err);
+    if let Err(errors) = load_envl("src/envl.rs".to_string()) {
+        let messages = errors.iter().map(render).collect::<Vec<_>>();
+        panic!("\n{}", messages.join("\n\n"));
     };
 }
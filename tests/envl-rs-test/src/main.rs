@@ -11,7 +11,12 @@ pub fn main() {
 
 #[cfg(test)]
 mod test {
-    use crate::envl::{envl, StructArrayw, StructStructev};
+    use std::collections::BTreeMap;
+
+    use crate::envl::{
+        envl, Database, EnumStructeu, EnumStructxlevel, EnumStructylevel, Enumj, Enumk, Level,
+        StructArrayStructew, StructStructev,
+    };
 
     #[test]
     fn env_check() {
@@ -27,7 +32,7 @@ mod test {
         assert_eq!(env.e.v, v);
         assert_eq!(
             env.e.w,
-            vec![StructArrayw {
+            vec![StructArrayStructew {
                 a: String::from("hi!")
             }]
         );
@@ -38,5 +43,31 @@ mod test {
         assert_eq!(env.g, 123);
         assert_eq!(env.h, Some(123));
         assert_eq!(env.i, None);
+        assert_eq!(env.e.u, EnumStructeu::Fast);
+        assert_eq!(env.j, Enumj::Info);
+        assert_eq!(env.k, Enumk::Off);
+        assert_eq!(
+            env.l,
+            BTreeMap::from([("tenant-a".to_string(), 10), ("tenant-b".to_string(), 20)])
+        );
+        assert_eq!(
+            env.m,
+            Database {
+                host: String::from("localhost"),
+                port: 5432
+            }
+        );
+        assert_eq!(
+            env.n.iter().map(|d| d.port).collect::<Vec<_>>(),
+            vec![5433, 5434]
+        );
+        assert_eq!(env.o, None);
+        assert_eq!(env.p, Level::High);
+        assert_eq!(env.q, 0.75);
+        assert_eq!(env.r, 8080);
+        assert_eq!(env.s, "line one\n  line two");
+        assert_eq!(env.t, None);
+        assert_eq!(env.x.level, Some(EnumStructxlevel::Low));
+        assert_eq!(env.y.level, Some(EnumStructylevel::Info));
     }
 }